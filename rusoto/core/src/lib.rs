@@ -10,6 +10,7 @@
 //! Rusoto is an [AWS](https://aws.amazon.com/) SDK for Rust.
 //! A high level overview is available in `README.md` at <https://github.com/rusoto/rusoto>.
 
+extern crate chrono;
 extern crate futures;
 extern crate hyper;
 extern crate hyper_tls;
@@ -42,6 +43,8 @@ pub use client_inner::ClientInner;
 #[doc(hidden)]
 pub mod serialization;
 #[doc(hidden)]
+pub mod xmlcredentials;
+#[doc(hidden)]
 pub mod xmlerror;
 #[doc(hidden)]
 pub mod xmlutil;
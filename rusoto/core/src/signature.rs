@@ -255,6 +255,12 @@ impl SignedRequest {
             self.add_header("content-length", &format!("{}", len));
         }
 
+        // anonymous requests are sent unsigned
+        if creds.is_anonymous() {
+            self.remove_header("authorization");
+            return;
+        }
+
         // use the hashed canonical request to build the string to sign
         let hashed_canonical_request = to_hexdigest(&canonical_request);
         let scope = format!("{}/{}/{}/aws4_request",
@@ -546,7 +552,7 @@ mod tests {
     use std::collections::BTreeMap;
     use time::empty_tm;
 
-    use credential::{ProvideAwsCredentials, ProfileProvider};
+    use credential::{AwsCredentials, ProvideAwsCredentials, ProfileProvider};
     use Region;
     use ::param::Params;
 
@@ -620,6 +626,24 @@ mod tests {
                    canonical_uri_string);
     }
 
+    #[test]
+    fn anonymous_requests_unsigned() {
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/public-bucket/key");
+        request.sign(&AwsCredentials::anonymous());
+        assert!(!request.headers().contains_key("authorization"));
+        assert!(request.headers().contains_key("x-amz-date"));
+
+        let provider = ProfileProvider::with_configuration("test_resources/multiple_profile_credentials",
+                                                           "foo");
+        request.sign(provider.credentials().wait().as_ref().unwrap());
+        assert!(request.headers().contains_key("authorization"));
+
+        // empty credentials, e.g. from a misconfigured provider, are still signed with
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/public-bucket/key");
+        request.sign(&AwsCredentials::new("", "", None, None));
+        assert!(request.headers().contains_key("authorization"));
+    }
+
     #[test]
     fn signature_generation() {
         let signature_foo = super::sign_string("foo", "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY", empty_tm(), "us-west-1", "s3");
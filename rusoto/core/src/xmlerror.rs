@@ -1,5 +1,6 @@
-use xmlutil::{XmlParseError, Peek, Next};
-use xmlutil::{characters, start_element, end_element, skip_tree, string_field, peek_at_name};
+use xml::reader::XmlEvent;
+use xmlutil::{XmlParseError, Peek, Next, PeekedName};
+use xmlutil::{characters, start_element, end_element, skip_tree, string_field, peek_name};
 
 #[derive(Default, Debug)]
 pub struct XmlError {
@@ -7,47 +8,615 @@ pub struct XmlError {
     pub code: String,
     pub message: String,
     pub detail: Option<String>,
+    exception_type: Option<String>,
+    exception_message: Option<String>,
+}
+
+/// Error codes AWS services use when a request is rejected because of rate limiting.
+const THROTTLING_CODES: &[&str] = &[
+    "Throttling",
+    "ThrottlingException",
+    "ThrottledException",
+    "RequestThrottledException",
+    "TooManyRequestsException",
+    "ProvisionedThroughputExceededException",
+    "TransactionInProgressException",
+    "RequestLimitExceeded",
+    "BandwidthLimitExceeded",
+    "LimitExceededException",
+    "RequestThrottled",
+    "SlowDown",
+    "PriorRequestNotComplete",
+    "EC2ThrottledException",
+];
+
+/// Error codes AWS services use for transient failures other than throttling.
+const TRANSIENT_CODES: &[&str] = &[
+    "RequestTimeout",
+    "RequestTimeoutException",
+    "InternalError",
+    "InternalFailure",
+    "ServiceUnavailable",
+    "ServiceUnavailableException",
+];
+
+impl XmlError {
+    /// whether the error code indicates that the request was throttled
+    pub fn is_throttling(&self) -> bool {
+        THROTTLING_CODES.contains(&&self.code[..])
+    }
+
+    /// whether the error code indicates a throttling or otherwise transient error, so
+    /// the request may succeed if retried
+    pub fn is_retryable(&self) -> bool {
+        self.is_throttling() || TRANSIENT_CODES.contains(&&self.code[..])
+    }
+
+    /// the `Type` of a nested `<Exception>` element, as returned by some newer services
+    pub fn exception_type(&self) -> Option<&str> {
+        self.exception_type.as_ref().map(|t| &t[..])
+    }
+
+    /// the `Message` of a nested `<Exception>` element
+    pub fn exception_message(&self) -> Option<&str> {
+        self.exception_message.as_ref().map(|m| &m[..])
+    }
+}
+
+/// the errors of a `<Response><Errors>...</Errors><RequestID>...</RequestID></Response>` body
+#[derive(Default, Debug)]
+pub struct XmlErrors {
+    pub errors: Vec<XmlError>,
+    pub request_id: Option<String>,
 }
 
 pub struct XmlErrorDeserializer;
 impl XmlErrorDeserializer {
+    /// deserialize the error element `tag_name`, which must be the next element in the stack
+    ///
+    /// On success, the stack is positioned right after the end tag of the error element,
+    /// so following siblings like `RequestId` can be read by the caller.
     pub fn deserialize<T: Peek + Next>(tag_name: &str,
                                        stack: &mut T)
                                        -> Result<XmlError, XmlParseError> {
-        start_element(tag_name, stack)?;
+        deserialize_error(tag_name, stack, &mut None)
+    }
+
+    /// deserialize the body of the error element `tag_name`, whose start tag the caller has
+    /// already consumed, e.g. after peeking at it to decide how to deserialize the response
+    ///
+    /// Reads the children of the error element and its end tag. On success, the stack is
+    /// positioned right after the end tag, just like after `deserialize`.
+    pub fn deserialize_from_inside<T: Peek + Next>(tag_name: &str,
+                                                   stack: &mut T)
+                                                   -> Result<XmlError, XmlParseError> {
+        deserialize_error_body(tag_name, stack, &mut None)
+    }
+
+    /// deserialize an error response of any known shape, detected by the name of the root
+    /// element, along with its request id if it has one
+    ///
+    /// Supported are `<ErrorResponse><Error>...</Error><RequestId>...</RequestId></ErrorResponse>`,
+    /// `<Response><Errors><Error>...</Error></Errors><RequestID>...</RequestID></Response>`,
+    /// of which only the first error is returned, and a bare `<Error>...</Error>` with an
+    /// optional `RequestId` inside, as returned by S3.
+    pub fn deserialize_any_error<T: Peek + Next>(stack: &mut T)
+                                                 -> Result<(XmlError, Option<String>), XmlParseError> {
+        let root = match next_child_name(stack)? {
+            Some(name) => name,
+            None => return Err(XmlParseError::new("Missing root element in XML error response")),
+        };
+        match &root[..] {
+            "ErrorResponse" => {
+                let error = XmlErrorDeserializer::deserialize_wrapped("ErrorResponse", "Error", stack)?;
+                let mut request_id = None;
+                while let Some(name) = next_child_name(stack)? {
+                    if name == "RequestId" {
+                        request_id = Some(string_field("RequestId", stack)?);
+                    } else {
+                        debug!("Ignoring unknown XML element {:?} in error response.", name);
+                        skip_tree(stack);
+                    }
+                }
+                end_element("ErrorResponse", stack)?;
+                Ok((error, request_id))
+            }
+            "Response" => {
+                let XmlErrors { errors, request_id } = XmlErrorDeserializer::deserialize_errors(stack)?;
+                // deserialize_errors fails if there is no error
+                let error = errors.into_iter().next().unwrap();
+                Ok((error, request_id))
+            }
+            "Error" => {
+                let mut request_id = None;
+                let error = deserialize_error("Error", stack, &mut request_id)?;
+                Ok((error, request_id))
+            }
+            unknown => Err(XmlParseError::new(&format!("Unknown XML error response element {:?}",
+                                                       unknown))),
+        }
+    }
+
+    /// consume the start tag of `wrapper_name`, e.g. `ErrorResponse`, and deserialize the
+    /// error element `tag_name` it contains
+    ///
+    /// On success, the stack is positioned right after the end tag of the error element,
+    /// inside the wrapper, ready for the caller to read the next sibling.
+    pub fn deserialize_wrapped<T: Peek + Next>(wrapper_name: &str,
+                                               tag_name: &str,
+                                               stack: &mut T)
+                                               -> Result<XmlError, XmlParseError> {
+        start_element(wrapper_name, stack)?;
+        XmlErrorDeserializer::deserialize(tag_name, stack)
+    }
+
+    /// deserialize a `Response` element, which must be the next element in the stack, with
+    /// all `Error` elements inside its `Errors` element and its `RequestID`
+    ///
+    /// On success, the stack is positioned right after the end tag of the `Response` element.
+    pub fn deserialize_errors<T: Peek + Next>(stack: &mut T) -> Result<XmlErrors, XmlParseError> {
+        start_element("Response", stack)?;
 
-        let mut obj = XmlError::default();
+        let mut obj = XmlErrors::default();
 
         loop {
-            match &peek_at_name(stack)?[..] {
-                "Type" => {
-                    obj.error_type = string_field("Type", stack)?;
-                }
-                "Code" => {
-                    obj.code = string_field("Code", stack)?;
+            match next_child_name(stack)? {
+                Some(ref name) if name == "Errors" => {
+                    start_element("Errors", stack)?;
+                    loop {
+                        match next_child_name(stack)? {
+                            Some(ref name) if name == "Error" => {
+                                obj.errors.push(XmlErrorDeserializer::deserialize("Error", stack)?);
+                            }
+                            Some(unknown) => {
+                                debug!("Ignoring unknown XML element {:?} in error response.", unknown);
+                                skip_tree(stack);
+                            }
+                            None => break,
+                        }
+                    }
+                    end_element("Errors", stack)?;
                 }
-                "Message" => {
-                    obj.message = string_field("Message", stack)?;
+                Some(ref name) if name == "RequestID" => {
+                    obj.request_id = Some(string_field("RequestID", stack)?);
                 }
-                "Detail" => {
-                    start_element("Detail", stack)?;
-                    if let Ok(characters) = characters(stack) {
-                        obj.detail = Some(characters.to_string());
-                        end_element("Detail", stack)?;
-                    }
-                },
-                "" => {
-                    break
-                },
-                unknown => {
+                Some(unknown) => {
                     debug!("Ignoring unknown XML element {:?} in error response.", unknown);
                     skip_tree(stack);
                 }
+                None => break,
             }
         }
 
-        end_element(tag_name, stack)?;
+        end_element("Response", stack)?;
 
+        if obj.errors.is_empty() {
+            return Err(XmlParseError::new("Missing Error element in XML response"));
+        }
         Ok(obj)
     }
 }
+
+/// deserialize the error element `tag_name`, storing a `RequestId` inside it, as used by
+/// S3, in `request_id`
+fn deserialize_error<T: Peek + Next>(tag_name: &str,
+                                     stack: &mut T,
+                                     request_id: &mut Option<String>)
+                                     -> Result<XmlError, XmlParseError> {
+    match peek_name(stack) {
+        PeekedName::Start(ref name) if name == tag_name => (),
+        PeekedName::Error(e) => return Err(truncated(&e)),
+        _ => return Err(XmlParseError::new(&format!("Missing {} element in XML response", tag_name))),
+    }
+    start_element(tag_name, stack)?;
+    deserialize_error_body(tag_name, stack, request_id)
+}
+
+/// deserialize the children and the end tag of the error element `tag_name`, whose start
+/// tag has already been consumed
+fn deserialize_error_body<T: Peek + Next>(tag_name: &str,
+                                          stack: &mut T,
+                                          request_id: &mut Option<String>)
+                                          -> Result<XmlError, XmlParseError> {
+    let mut obj = XmlError::default();
+
+    loop {
+        let name = match peek_name(stack) {
+            PeekedName::Start(name) => name,
+            PeekedName::Error(e) => return Err(truncated(&e)),
+            PeekedName::Other(description) => {
+                if let Some(&Ok(XmlEvent::EndElement { .. })) = stack.peek() {
+                    break;
+                }
+                debug!("Ignoring unexpected XML event {} in error response.", description);
+                stack.next();
+                continue;
+            }
+            PeekedName::End => break,
+        };
+        match &name[..] {
+            "Type" => {
+                obj.error_type = string_field("Type", stack)?;
+            }
+            "Code" => {
+                obj.code = string_field("Code", stack)?;
+            }
+            "Message" => {
+                obj.message = string_field("Message", stack)?;
+            }
+            "Detail" => {
+                start_element("Detail", stack)?;
+                if let Ok(characters) = characters(stack) {
+                    obj.detail = Some(characters.to_string());
+                    end_element("Detail", stack)?;
+                }
+            },
+            "Exception" => {
+                start_element("Exception", stack)?;
+                while let Some(name) = next_child_name(stack)? {
+                    match &name[..] {
+                        "Type" => obj.exception_type = Some(string_field("Type", stack)?),
+                        "Message" => obj.exception_message = Some(string_field("Message", stack)?),
+                        unknown => {
+                            debug!("Ignoring unknown XML element {:?} in error response.", unknown);
+                            skip_tree(stack);
+                        }
+                    }
+                }
+                end_element("Exception", stack)?;
+            }
+            "RequestId" => {
+                *request_id = Some(string_field("RequestId", stack)?);
+            }
+            unknown => {
+                debug!("Ignoring unknown XML element {:?} in error response.", unknown);
+                skip_tree(stack);
+            }
+        }
+    }
+
+    end_element(tag_name, stack)?;
+
+    Ok(obj)
+}
+
+/// cheaply check whether `bytes`, the start of a response body, looks like an AWS error
+/// response, to decide whether to run the full `XmlErrorDeserializer`
+///
+/// Only the root element is looked at, skipping a byte order mark, the XML declaration,
+/// comments and whitespace. Its local name has to be `Error`, `ErrorResponse` or
+/// `Response`, as used by EC2. To never miss an error, a buffer ending before the root
+/// element's name is complete looks like an error too, unless it's blank.
+pub fn looks_like_error(bytes: &[u8]) -> bool {
+    let mut rest = if bytes.starts_with(b"\xEF\xBB\xBF") { &bytes[3..] } else { bytes };
+    let mut seen_markup = false;
+    loop {
+        rest = trim_start(rest);
+        if rest.is_empty() {
+            return seen_markup;
+        }
+        // skip the declaration, processing instructions, comments and a DOCTYPE
+        let end_of_markup = if rest.starts_with(b"<?") {
+            find(rest, b"?>").map(|end| end + 2)
+        } else if rest.starts_with(b"<!--") {
+            find(rest, b"-->").map(|end| end + 3)
+        } else if rest.starts_with(b"<!") {
+            find(rest, b">").map(|end| end + 1)
+        } else if rest.starts_with(b"<") {
+            let name = &rest[1..];
+            return match name.iter().position(|&b| is_whitespace(b) || b == b'>' || b == b'/') {
+                Some(end) => {
+                    let name = &name[..end];
+                    let local_name = match name.iter().rposition(|&b| b == b':') {
+                        Some(colon) => &name[colon + 1..],
+                        None => name,
+                    };
+                    local_name == b"Error" || local_name == b"ErrorResponse" || local_name == b"Response"
+                }
+                // the name may continue in the next buffer
+                None => true,
+            };
+        } else {
+            // not XML at all
+            return false;
+        };
+        match end_of_markup {
+            Some(end) => rest = &rest[end..],
+            // the body may continue in the next buffer
+            None => return true,
+        }
+        seen_markup = true;
+    }
+}
+
+fn is_whitespace(b: u8) -> bool {
+    b == b' ' || b == b'\t' || b == b'\r' || b == b'\n'
+}
+
+fn trim_start(bytes: &[u8]) -> &[u8] {
+    match bytes.iter().position(|&b| !is_whitespace(b)) {
+        Some(start) => &bytes[start..],
+        None => &[],
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+/// the name of the next child element, skipping over other events, or `None` at the end
+/// of the parent element
+fn next_child_name<T: Peek + Next>(stack: &mut T) -> Result<Option<String>, XmlParseError> {
+    loop {
+        match peek_name(stack) {
+            PeekedName::Start(name) => return Ok(Some(name)),
+            PeekedName::Error(e) => return Err(truncated(&e)),
+            PeekedName::Other(description) => {
+                if let Some(&Ok(XmlEvent::EndElement { .. })) = stack.peek() {
+                    return Ok(None);
+                }
+                debug!("Ignoring unexpected XML event {} in error response.", description);
+                stack.next();
+            }
+            PeekedName::End => return Ok(None),
+        }
+    }
+}
+
+fn truncated(cause: &str) -> XmlParseError {
+    XmlParseError::new(&format!("Truncated XML response: {}", cause))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xml::reader::EventReader;
+    use xmlutil::{XmlResponse, find_start_element};
+
+    fn deserialize(body: &[u8]) -> Result<XmlError, XmlParseError> {
+        let parser = EventReader::new(body);
+        let mut stack = XmlResponse::new(parser);
+        find_start_element(&mut stack);
+        start_element("ErrorResponse", &mut stack)?;
+        XmlErrorDeserializer::deserialize("Error", &mut stack)
+    }
+
+    #[test]
+    fn deserialize_complete_error() {
+        let error = deserialize(b"<ErrorResponse><Error><Type>Sender</Type><Code>Throttling</Code>\
+                                  <Message>Rate exceeded</Message></Error></ErrorResponse>").unwrap();
+        assert_eq!(error.error_type, "Sender");
+        assert_eq!(error.code, "Throttling");
+        assert_eq!(error.message, "Rate exceeded");
+    }
+
+    #[test]
+    fn deserialize_error_with_exception() {
+        let error = deserialize(b"<ErrorResponse><Error><Code>InvalidInput</Code>\
+                                  <Message>Invalid input</Message><Exception>\
+                                  <Type>ValidationException</Type><Message>Name is too long</Message>\
+                                  <Unknown><Nested/></Unknown></Exception></Error></ErrorResponse>")
+            .unwrap();
+        assert_eq!(error.code, "InvalidInput");
+        assert_eq!(error.message, "Invalid input");
+        assert_eq!(error.exception_type(), Some("ValidationException"));
+        assert_eq!(error.exception_message(), Some("Name is too long"));
+
+        let error = deserialize(b"<ErrorResponse><Error><Code>InvalidInput</Code></Error></ErrorResponse>")
+            .unwrap();
+        assert_eq!(error.exception_type(), None);
+        assert_eq!(error.exception_message(), None);
+    }
+
+    #[test]
+    fn deserialize_error_with_stray_text() {
+        let error = deserialize(b"<ErrorResponse><Error><Code>Throttling</Code>stray\
+                                  <Message>Rate exceeded</Message></Error></ErrorResponse>").unwrap();
+        assert_eq!(error.code, "Throttling");
+        assert_eq!(error.message, "Rate exceeded");
+    }
+
+    #[test]
+    fn deserialize_wrapped_leaves_siblings() {
+        let body = b"<ErrorResponse><Error><Code>Throttling</Code></Error>\
+                     <RequestId>42d59b56-7407-4c4a-be0f-4c88daeea257</RequestId></ErrorResponse>";
+        let parser = EventReader::new(&body[..]);
+        let mut stack = XmlResponse::new(parser);
+        find_start_element(&mut stack);
+
+        let error = XmlErrorDeserializer::deserialize_wrapped("ErrorResponse", "Error", &mut stack).unwrap();
+        assert_eq!(error.code, "Throttling");
+        assert_eq!(string_field("RequestId", &mut stack).unwrap(), "42d59b56-7407-4c4a-be0f-4c88daeea257");
+        end_element("ErrorResponse", &mut stack).unwrap();
+    }
+
+    #[test]
+    fn deserialize_with_and_without_start_tag() {
+        let body = b"<Error><Code>Throttling</Code><Message>Rate exceeded</Message></Error>\
+                     <RequestId>42d59b56-7407-4c4a-be0f-4c88daeea257</RequestId>";
+        for &consume_start_tag in &[false, true] {
+            let mut wrapped = b"<ErrorResponse>".to_vec();
+            wrapped.extend_from_slice(&body[..]);
+            wrapped.extend_from_slice(b"</ErrorResponse>");
+            let parser = EventReader::new(&wrapped[..]);
+            let mut stack = XmlResponse::new(parser);
+            find_start_element(&mut stack);
+            start_element("ErrorResponse", &mut stack).unwrap();
+
+            let error = if consume_start_tag {
+                start_element("Error", &mut stack).unwrap();
+                XmlErrorDeserializer::deserialize_from_inside("Error", &mut stack).unwrap()
+            } else {
+                XmlErrorDeserializer::deserialize("Error", &mut stack).unwrap()
+            };
+            assert_eq!(error.code, "Throttling");
+            assert_eq!(error.message, "Rate exceeded");
+            assert_eq!(string_field("RequestId", &mut stack).unwrap(),
+                       "42d59b56-7407-4c4a-be0f-4c88daeea257");
+            end_element("ErrorResponse", &mut stack).unwrap();
+        }
+    }
+
+    #[test]
+    fn classify_error_codes() {
+        fn error(code: &str) -> XmlError {
+            XmlError { code: code.to_owned(), ..XmlError::default() }
+        }
+
+        for code in &["Throttling", "ThrottlingException", "RequestLimitExceeded", "SlowDown"] {
+            assert!(error(code).is_throttling(), "{}", code);
+            assert!(error(code).is_retryable(), "{}", code);
+        }
+        for code in &["RequestTimeout", "ServiceUnavailable", "InternalError"] {
+            assert!(!error(code).is_throttling(), "{}", code);
+            assert!(error(code).is_retryable(), "{}", code);
+        }
+        for code in &["AccessDenied", "ValidationError", "throttling", ""] {
+            assert!(!error(code).is_throttling(), "{}", code);
+            assert!(!error(code).is_retryable(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn deserialize_errors_response() {
+        let body = b"<Response><Errors>\
+                     <Error><Code>InvalidInstanceID.NotFound</Code><Message>Not found</Message></Error>\
+                     <Error><Code>RequestLimitExceeded</Code><Message>Rate exceeded</Message></Error>\
+                     </Errors><RequestID>ea966190-f9aa-478e-9ede-cb5432daacc0</RequestID></Response>";
+        let parser = EventReader::new(&body[..]);
+        let mut stack = XmlResponse::new(parser);
+        find_start_element(&mut stack);
+
+        let errors = XmlErrorDeserializer::deserialize_errors(&mut stack).unwrap();
+        assert_eq!(errors.errors.len(), 2);
+        assert_eq!(errors.errors[0].code, "InvalidInstanceID.NotFound");
+        assert_eq!(errors.errors[0].message, "Not found");
+        assert_eq!(errors.errors[1].code, "RequestLimitExceeded");
+        assert_eq!(errors.errors[1].message, "Rate exceeded");
+        assert_eq!(errors.request_id, Some("ea966190-f9aa-478e-9ede-cb5432daacc0".to_owned()));
+    }
+
+    fn deserialize_any(body: &[u8]) -> Result<(XmlError, Option<String>), XmlParseError> {
+        let parser = EventReader::new(body);
+        let mut stack = XmlResponse::new(parser);
+        XmlErrorDeserializer::deserialize_any_error(&mut stack)
+    }
+
+    #[test]
+    fn deserialize_any_error_response() {
+        let (error, request_id) = deserialize_any(b"<?xml version=\"1.0\"?>\
+            <ErrorResponse xmlns=\"https://sts.amazonaws.com/doc/2011-06-15/\">\
+            <Error><Type>Sender</Type><Code>AccessDenied</Code><Message>Denied</Message></Error>\
+            <RequestId>42d59b56-7407-4c4a-be0f-4c88daeea257</RequestId></ErrorResponse>").unwrap();
+        assert_eq!(error.error_type, "Sender");
+        assert_eq!(error.code, "AccessDenied");
+        assert_eq!(error.message, "Denied");
+        assert_eq!(request_id, Some("42d59b56-7407-4c4a-be0f-4c88daeea257".to_owned()));
+    }
+
+    #[test]
+    fn deserialize_any_errors_response() {
+        let (error, request_id) = deserialize_any(b"<Response><Errors>\
+            <Error><Code>InvalidInstanceID.NotFound</Code><Message>Not found</Message></Error>\
+            <Error><Code>RequestLimitExceeded</Code></Error>\
+            </Errors><RequestID>ea966190-f9aa-478e-9ede-cb5432daacc0</RequestID></Response>").unwrap();
+        assert_eq!(error.code, "InvalidInstanceID.NotFound");
+        assert_eq!(error.message, "Not found");
+        assert_eq!(request_id, Some("ea966190-f9aa-478e-9ede-cb5432daacc0".to_owned()));
+    }
+
+    #[test]
+    fn deserialize_any_bare_error() {
+        let (error, request_id) = deserialize_any(b"<Error><Code>InvalidParameterValue</Code>\
+            <Message>Bad value</Message></Error>").unwrap();
+        assert_eq!(error.code, "InvalidParameterValue");
+        assert_eq!(error.message, "Bad value");
+        assert_eq!(request_id, None);
+    }
+
+    #[test]
+    fn deserialize_any_s3_error() {
+        let (error, request_id) = deserialize_any(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <Error><Code>NoSuchKey</Code><Message>The specified key does not exist.</Message>\
+            <Key>photos/2006/February/sample.jpg</Key><RequestId>4442587FB7D0A2F9</RequestId>\
+            <HostId>eftixk72aD6Ap51TnqcoF8eFidJG9Z/2mkiDFu8yU9AS1ed4OpIszj7UDNEHGran</HostId>\
+            </Error>").unwrap();
+        assert_eq!(error.code, "NoSuchKey");
+        assert_eq!(error.message, "The specified key does not exist.");
+        assert_eq!(request_id, Some("4442587FB7D0A2F9".to_owned()));
+    }
+
+    #[test]
+    fn deserialize_prefixed_elements() {
+        let body = b"<aws:ErrorResponse xmlns:aws=\"https://sts.amazonaws.com/doc/2011-06-15/\">\
+                     <aws:Error><aws:Type>Sender</aws:Type><aws:Code>AccessDenied</aws:Code>\
+                     <aws:Message>Denied</aws:Message></aws:Error>\
+                     <aws:RequestId>42d59b56-7407-4c4a-be0f-4c88daeea257</aws:RequestId>\
+                     </aws:ErrorResponse>";
+        let (error, request_id) = deserialize_any(&body[..]).unwrap();
+        assert_eq!(error.error_type, "Sender");
+        assert_eq!(error.code, "AccessDenied");
+        assert_eq!(error.message, "Denied");
+        assert_eq!(request_id, Some("42d59b56-7407-4c4a-be0f-4c88daeea257".to_owned()));
+
+        let error = deserialize(&body[..]).unwrap();
+        assert_eq!(error.code, "AccessDenied");
+
+        let (error, request_id) = deserialize_any(b"<ec2:Response xmlns:ec2=\"http://ec2.amazonaws.com/doc/2016-11-15/\">\
+            <ec2:Errors><ec2:Error><ec2:Code>RequestLimitExceeded</ec2:Code></ec2:Error></ec2:Errors>\
+            <ec2:RequestID>ea966190-f9aa-478e-9ede-cb5432daacc0</ec2:RequestID></ec2:Response>").unwrap();
+        assert_eq!(error.code, "RequestLimitExceeded");
+        assert_eq!(request_id, Some("ea966190-f9aa-478e-9ede-cb5432daacc0".to_owned()));
+    }
+
+    #[test]
+    fn sniff_error_bodies() {
+        let errors: &[&[u8]] = &[
+            b"<?xml version=\"1.0\"?>\n<ErrorResponse xmlns=\"https://sts.amazonaws.com/doc/2011-06-15/\">\
+              <Error><Code>AccessDenied</Code></Error></ErrorResponse>",
+            b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Error><Code>NoSuchKey</Code></Error>",
+            b"<Response><Errors><Error><Code>RequestLimitExceeded</Code></Error></Errors></Response>",
+            b"<aws:ErrorResponse xmlns:aws=\"https://sts.amazonaws.com/doc/2011-06-15/\">",
+            b"\xEF\xBB\xBF<!-- comment -->\r\n<Error/>",
+            // partial buffers, which may still turn out to be errors
+            b"<?xml version=\"1.0\"?><Err",
+            b"<?xml version=\"1.0\" encoding=",
+        ];
+        for body in errors {
+            assert!(looks_like_error(body), "{}", String::from_utf8_lossy(body));
+        }
+
+        let successes: &[&[u8]] = &[
+            b"<?xml version=\"1.0\"?>\n<ListQueuesResponse><ListQueuesResult/></ListQueuesResponse>",
+            b"<DescribeInstancesResponse xmlns=\"http://ec2.amazonaws.com/doc/2016-11-15/\">",
+            b"<ErrorsResponse/>",
+            b"<Errors>",
+            b"{\"__type\": \"ValidationException\"}",
+            b"",
+            b" \n",
+        ];
+        for body in successes {
+            assert!(!looks_like_error(body), "{}", String::from_utf8_lossy(body));
+        }
+    }
+
+    #[test]
+    fn deserialize_any_unknown_shape() {
+        assert_eq!(deserialize_any(b"<Oops><Code>x</Code></Oops>").unwrap_err(),
+                   XmlParseError::new("Unknown XML error response element \"Oops\""));
+        let XmlParseError(message) = deserialize_any(b"").unwrap_err();
+        assert!(message.starts_with("Truncated XML response"), "{}", message);
+    }
+
+    #[test]
+    fn deserialize_truncated_error() {
+        let XmlParseError(message) = deserialize(b"<ErrorResponse><Error><Code>Throttling</Code>").unwrap_err();
+        assert!(message.starts_with("Truncated XML response"), "{}", message);
+    }
+
+    #[test]
+    fn deserialize_missing_error_element() {
+        let XmlParseError(message) = deserialize(b"<ErrorResponse><RequestId>abc</RequestId></ErrorResponse>").unwrap_err();
+        assert_eq!(message, "Missing Error element in XML response");
+    }
+}
@@ -3,14 +3,19 @@
 //! Wraps an XML stack via traits.
 //! Also provides a method of supplying an XML stack from a file for testing purposes.
 
+use std::io::{Cursor, Read};
 use std::iter::Peekable;
 use std::num::ParseIntError;
+use std::time::Duration;
 use std::collections::HashMap;
-use xml::reader::{Events, XmlEvent};
+use time::{self, Timespec, Tm};
+use url::percent_encoding::percent_decode;
+use xml::common::{Position, TextPosition};
+use xml::reader::{EventReader, Events, XmlEvent};
 use xml;
 
 /// generic Error for XML parsing
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct XmlParseError(pub String);
 
 impl XmlParseError {
@@ -25,45 +30,254 @@ pub type XmlStack<'a> = Peekable<Events<&'a [u8]>>;
 /// Peek at next items in the XML stack
 pub trait Peek {
     fn peek(&mut self) -> Option<&Result<XmlEvent, xml::reader::Error>>;
+
+    /// like `peek`, but without skipping whitespace-only text, e.g. between two CDATA
+    /// sections of the same text; the same as `peek` unless overridden
+    fn peek_text(&mut self) -> Option<&Result<XmlEvent, xml::reader::Error>> {
+        self.peek()
+    }
 }
 
 /// Move to the next part of the XML stack
 pub trait Next {
     fn next(&mut self) -> Option<Result<XmlEvent, xml::reader::Error>>;
+
+    /// like `next`, but without skipping whitespace-only text, the counterpart of
+    /// `Peek::peek_text`
+    fn next_text(&mut self) -> Option<Result<XmlEvent, xml::reader::Error>> {
+        self.next()
+    }
 }
 
 /// Wraps the Hyper Response type
+///
+/// By default, the document structure events `StartDocument`, `EndDocument` and
+/// `ProcessingInstruction`, as well as comments and whitespace-only text, are skipped, so
+/// deserializers only see elements and their content.
 pub struct XmlResponse<'b> {
-    xml_stack: Peekable<Events<&'b [u8]>>, // refactor to use XmlStack type?
+    xml_stack: Peekable<PositionedEvents<&'b [u8]>>,
+    /// the position of the last consumed event
+    position: TextPosition,
+    skip: Skip,
+}
+
+/// which events an `XmlResponse` skips
+#[derive(Clone, Copy)]
+struct Skip {
+    whitespace: bool,
+    document_events: bool,
+    blank_text: bool,
+}
+
+impl Default for Skip {
+    fn default() -> Skip {
+        Skip {
+            whitespace: true,
+            document_events: true,
+            blank_text: true,
+        }
+    }
+}
+
+impl Skip {
+    /// these settings, but keeping whitespace-only text
+    fn keeping_text(&self) -> Skip {
+        Skip {
+            whitespace: false,
+            blank_text: false,
+            ..*self
+        }
+    }
+
+    /// whether `event` is skipped with these settings
+    fn skips(&self, event: &Result<XmlEvent, xml::reader::Error>) -> bool {
+        match *event {
+            Ok(XmlEvent::Whitespace(_)) => self.whitespace,
+            Ok(XmlEvent::Characters(ref text)) => {
+                self.blank_text && text.chars().all(char::is_whitespace)
+            }
+            Ok(XmlEvent::StartDocument { .. }) |
+            Ok(XmlEvent::EndDocument) |
+            Ok(XmlEvent::ProcessingInstruction { .. }) |
+            Ok(XmlEvent::Comment(_)) => self.document_events,
+            _ => false,
+        }
+    }
 }
 
 impl<'b> XmlResponse<'b> {
-    pub fn new(stack: Peekable<Events<&'b [u8]>>) -> XmlResponse {
-        XmlResponse { xml_stack: stack }
+    pub fn new(reader: EventReader<&'b [u8]>) -> XmlResponse {
+        XmlResponse {
+            xml_stack: PositionedEvents { reader: reader, finished: false }.peekable(),
+            position: TextPosition::new(),
+            skip: Skip::default(),
+        }
+    }
+
+    /// whether whitespace-only text is skipped, which is the default
+    ///
+    /// With skipping disabled, `characters` returns the exact content of elements containing
+    /// only whitespace, but whitespace between elements, like indentation, has to be
+    /// consumed by the caller.
+    pub fn set_skip_whitespace(&mut self, skip_whitespace: bool) {
+        self.skip.whitespace = skip_whitespace;
+    }
+
+    /// whether `StartDocument`, `EndDocument`, `ProcessingInstruction` and `Comment` events
+    /// are skipped, which is the default
+    ///
+    /// Disable skipping for raw access to the XML declaration, e.g. using
+    /// `consume_declaration`.
+    pub fn set_skip_document_events(&mut self, skip_document_events: bool) {
+        self.skip.document_events = skip_document_events;
+    }
+
+    /// whether `Characters` events consisting only of whitespace are skipped, which is the
+    /// default
+    ///
+    /// Some servers, or parsers configured with `whitespace_to_characters`, emit whitespace
+    /// between elements as `Characters` rather than `Whitespace`. Disable skipping to get
+    /// the exact content of elements containing only such text.
+    pub fn set_skip_blank_text(&mut self, skip_blank_text: bool) {
+        self.skip.blank_text = skip_blank_text;
+    }
+
+    /// the position of the start of the last consumed event, e.g. for logging progress
+    pub fn position(&self) -> TextPosition {
+        self.position
+    }
+
+    /// peek at the next event not skipped with the given settings
+    fn peek_skipping(&mut self, skip: Skip) -> Option<&Result<XmlEvent, xml::reader::Error>> {
+        loop {
+            match self.xml_stack.peek() {
+                Some(&(ref event, _)) if skip.skips(event) => {}
+                _ => break,
+            }
+            self.xml_stack.next();
+        }
+        self.xml_stack.peek().map(|&(ref event, _)| event)
+    }
+
+    /// the next event not skipped with the given settings
+    fn next_skipping(&mut self, skip: Skip) -> Option<Result<XmlEvent, xml::reader::Error>> {
+        loop {
+            match self.xml_stack.next() {
+                Some((ref event, _)) if skip.skips(event) => {}
+                Some((event, position)) => {
+                    self.position = position;
+                    return Some(event);
+                }
+                None => return None,
+            }
+        }
     }
 }
 
 impl<'b> Peek for XmlResponse<'b> {
     fn peek(&mut self) -> Option<&Result<XmlEvent, xml::reader::Error>> {
-        while let Some(&Ok(XmlEvent::Whitespace(_))) = self.xml_stack.peek() {
-            self.xml_stack.next();
-        }
-        self.xml_stack.peek()
+        let skip = self.skip;
+        self.peek_skipping(skip)
+    }
+
+    fn peek_text(&mut self) -> Option<&Result<XmlEvent, xml::reader::Error>> {
+        let skip = self.skip.keeping_text();
+        self.peek_skipping(skip)
     }
 }
 
 impl<'b> Next for XmlResponse<'b> {
     fn next(&mut self) -> Option<Result<XmlEvent, xml::reader::Error>> {
-        let mut maybe_event;
-        loop {
-            maybe_event = self.xml_stack.next();
-            match maybe_event {
-                Some(Ok(XmlEvent::Whitespace(_))) => {}
-                _ => break,
-            }
+        let skip = self.skip;
+        self.next_skipping(skip)
+    }
+
+    fn next_text(&mut self) -> Option<Result<XmlEvent, xml::reader::Error>> {
+        let skip = self.skip.keeping_text();
+        self.next_skipping(skip)
+    }
+}
+
+/// like `Events`, but also yields the position of each event
+struct PositionedEvents<R: Read> {
+    reader: EventReader<R>,
+    finished: bool,
+}
+
+impl<R: Read> Iterator for PositionedEvents<R> {
+    type Item = (Result<XmlEvent, xml::reader::Error>, TextPosition);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let event = self.reader.next();
+        // like `Events`, stop after the end of the document or an error
+        match event {
+            Ok(XmlEvent::EndDocument) | Err(_) => self.finished = true,
+            _ => (),
+        }
+        Some((event, self.reader.position()))
+    }
+}
+
+/// Like `XmlResponse`, but owns the XML body, so it can be returned from functions
+/// without keeping the body alive separately
+pub struct OwnedXmlResponse {
+    xml_stack: Peekable<Events<Cursor<Vec<u8>>>>,
+}
+
+impl OwnedXmlResponse {
+    pub fn new(body: Vec<u8>) -> OwnedXmlResponse {
+        OwnedXmlResponse { xml_stack: EventReader::new(Cursor::new(body)).into_iter().peekable() }
+    }
+}
+
+impl Peek for OwnedXmlResponse {
+    fn peek(&mut self) -> Option<&Result<XmlEvent, xml::reader::Error>> {
+        peek_skipping(&mut self.xml_stack, Skip::default())
+    }
+
+    fn peek_text(&mut self) -> Option<&Result<XmlEvent, xml::reader::Error>> {
+        peek_skipping(&mut self.xml_stack, Skip::default().keeping_text())
+    }
+}
+
+impl Next for OwnedXmlResponse {
+    fn next(&mut self) -> Option<Result<XmlEvent, xml::reader::Error>> {
+        next_skipping(&mut self.xml_stack, Skip::default())
+    }
+
+    fn next_text(&mut self) -> Option<Result<XmlEvent, xml::reader::Error>> {
+        next_skipping(&mut self.xml_stack, Skip::default().keeping_text())
+    }
+}
+
+fn peek_skipping<I>(stack: &mut Peekable<I>, skip: Skip) -> Option<&Result<XmlEvent, xml::reader::Error>>
+    where I: Iterator<Item = Result<XmlEvent, xml::reader::Error>>
+{
+    loop {
+        match stack.peek() {
+            Some(event) if skip.skips(event) => {}
+            _ => break,
         }
-        maybe_event
+        stack.next();
     }
+    stack.peek()
+}
+
+fn next_skipping<I>(stack: &mut Peekable<I>, skip: Skip) -> Option<Result<XmlEvent, xml::reader::Error>>
+    where I: Iterator<Item = Result<XmlEvent, xml::reader::Error>>
+{
+    let mut maybe_event;
+    loop {
+        maybe_event = stack.next();
+        match maybe_event {
+            Some(ref event) if skip.skips(event) => {}
+            _ => break,
+        }
+    }
+    maybe_event
 }
 
 impl From<ParseIntError> for XmlParseError {
@@ -80,7 +294,414 @@ pub fn string_field<T: Peek + Next>(name: &str, stack: &mut T) -> Result<String,
     Ok(value)
 }
 
+/// return a string field with the right name, or `None` if it's marked as null using
+/// `xsi:nil="true"` (matched by local name), or throw a parse error
+///
+/// An empty element without `xsi:nil` is returned as an empty string.
+pub fn optional_string_field<T: Peek + Next>(name: &str,
+                                             stack: &mut T)
+                                             -> Result<Option<String>, XmlParseError> {
+    let attributes = try!(start_element(name, stack));
+    let value = try!(characters(stack));
+    try!(end_element(name, stack));
+    if attributes.get("nil").map_or(false, |nil| nil == "true") {
+        Ok(None)
+    } else {
+        Ok(Some(value))
+    }
+}
+
+/// consume the start tag of an element with the right name, parse its content using `body`,
+/// then consume the matching end tag, or throw a parse error
+///
+/// `body` has to consume exactly the content of the element, e.g. its child fields.
+pub fn struct_field<T, S, F>(name: &str, stack: &mut S, body: F) -> Result<T, XmlParseError>
+    where S: Peek + Next,
+          F: FnOnce(&mut S) -> Result<T, XmlParseError>
+{
+    try!(start_element(name, stack));
+    let value = try!(body(stack));
+    try!(end_element(name, stack));
+    Ok(value)
+}
+
+/// the content of an element parsed by `text_or_struct`
+#[derive(Debug, PartialEq)]
+pub enum TextOrStruct<T> {
+    /// the element contained plain text, or nothing
+    Text(String),
+    /// the element contained child elements, parsed into a value
+    Struct(T),
+}
+
+/// consume an element with the right name which contains either plain text or child
+/// elements, or throw a parse error
+///
+/// If the first content of the element is a child element, `struct_parser` is called to
+/// consume the content, like the `body` of `struct_field`, otherwise the text is returned.
+pub fn text_or_struct<T, S, F>(name: &str,
+                               stack: &mut S,
+                               struct_parser: F)
+                               -> Result<TextOrStruct<T>, XmlParseError>
+    where S: Peek + Next,
+          F: FnOnce(&mut S) -> Result<T, XmlParseError>
+{
+    try!(start_element(name, stack));
+    let value = match peek_name(stack) {
+        PeekedName::Start(_) => TextOrStruct::Struct(try!(struct_parser(stack))),
+        _ => TextOrStruct::Text(try!(characters(stack))),
+    };
+    try!(end_element(name, stack));
+    Ok(value)
+}
+
+/// return whether the next element has the right name, for elements whose mere presence
+/// marks a flag as set, e.g. `<IsDefault/>`
+///
+/// A matching element is consumed along with any content, otherwise nothing is consumed.
+pub fn presence_flag<T: Peek + Next>(name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    match peek_name(stack) {
+        PeekedName::Start(ref element_name) if element_name == name => {
+            skip_tree(stack);
+            Ok(true)
+        }
+        PeekedName::Error(e) => Err(XmlParseError::new(&format!("Invalid XML document: {}", e))),
+        _ => Ok(false),
+    }
+}
+
+/// return a boolean field with the right name, which must be `true` or `false` as used in
+/// AWS responses, or throw a parse error
+pub fn bool_field<T: Peek + Next>(name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    let value = try!(string_field(name, stack));
+    match value.trim() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        other => Err(XmlParseError::new(&format!("Invalid boolean {:?} in {}", other, name))),
+    }
+}
+
+/// like `bool_field`, but also accept config-style spellings: `true`/`false`, `yes`/`no`
+/// and `on`/`off`, all case-insensitive
+pub fn lenient_bool_field<T: Peek + Next>(name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    let value = try!(string_field(name, stack));
+    match &value.trim().to_lowercase()[..] {
+        "true" | "yes" | "on" => Ok(true),
+        "false" | "no" | "off" => Ok(false),
+        _ => Err(XmlParseError::new(&format!("Invalid boolean {:?} in {}", value.trim(), name))),
+    }
+}
+
+/// return the entries of a string field with the right name whose text is a comma-separated
+/// list, e.g. `<Caps>a, b</Caps>`, or throw a parse error
+///
+/// Entries are trimmed, empty ones are dropped, so empty content yields an empty list.
+pub fn csv_field<T: Peek + Next>(name: &str, stack: &mut T) -> Result<Vec<String>, XmlParseError> {
+    let value = try!(string_field(name, stack));
+    Ok(value.split(',')
+        .map(|entry| entry.trim())
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| entry.to_owned())
+        .collect())
+}
+
+/// return the values of consecutive string fields with the right name, stopping at the
+/// first element with another name or the end of the parent element
+pub fn string_list_field<T: Peek + Next>(item_name: &str,
+                                         stack: &mut T)
+                                         -> Result<Vec<String>, XmlParseError> {
+    let mut values = Vec::new();
+    while try!(peek_at_name(stack)) == item_name {
+        values.push(try!(string_field(item_name, stack)));
+    }
+    Ok(values)
+}
+
+/// return the items of a list wrapped in an element with the right name, each parsed by
+/// `parser` from an element named `item_name`, or throw a parse error
+pub fn wrapped_list_field<T, U, F>(wrapper_name: &str,
+                                   item_name: &str,
+                                   stack: &mut T,
+                                   mut parser: F)
+                                   -> Result<Vec<U>, XmlParseError>
+    where T: Peek + Next,
+          F: FnMut(&str, &mut T) -> Result<U, XmlParseError>
+{
+    try!(start_element(wrapper_name, stack));
+    let mut items = Vec::new();
+    while try!(peek_at_name(stack)) == item_name {
+        items.push(try!(parser(item_name, stack)));
+    }
+    try!(end_element(wrapper_name, stack));
+    Ok(items)
+}
+
+/// like `wrapped_list_field`, but return `None` if the next element isn't the wrapper
+/// element, to tell an omitted list apart from an empty one
+pub fn optional_wrapped_list_field<T, U, F>(wrapper_name: &str,
+                                            item_name: &str,
+                                            stack: &mut T,
+                                            parser: F)
+                                            -> Result<Option<Vec<U>>, XmlParseError>
+    where T: Peek + Next,
+          F: FnMut(&str, &mut T) -> Result<U, XmlParseError>
+{
+    if try!(peek_at_name(stack)) != wrapper_name {
+        return Ok(None);
+    }
+    wrapped_list_field(wrapper_name, item_name, stack, parser).map(Some)
+}
+
+/// parse the next element, whose local name is the discriminant of a polymorphic value, by
+/// calling `parser` with the name, or throw a parse error if the name isn't in `allowed`
+///
+/// Nothing is consumed before `parser` is called, so it can parse the whole element.
+pub fn parse_tagged_enum<T, S, F>(allowed: &[&str], stack: &mut S, parser: F) -> Result<T, XmlParseError>
+    where S: Peek + Next,
+          F: Fn(&str, &mut S) -> Result<T, XmlParseError>
+{
+    match peek_name(stack) {
+        PeekedName::Start(ref name) if allowed.contains(&&name[..]) => parser(name, stack),
+        PeekedName::Start(name) => {
+            Err(XmlParseError::new(&format!("Unexpected element {}, expected one of {}",
+                                            name,
+                                            allowed.join(", "))))
+        }
+        PeekedName::Error(e) => Err(XmlParseError::new(&format!("Invalid XML document: {}", e))),
+        PeekedName::Other(description) => {
+            Err(XmlParseError::new(&format!("Expected one of {} got {}", allowed.join(", "), description)))
+        }
+        PeekedName::End => {
+            Err(XmlParseError::new(&format!("Expected one of {} got end of document", allowed.join(", "))))
+        }
+    }
+}
+
+/// a child element of an element deserialized by `deserialize_struct`
+pub struct Field<'a, S: 'a> {
+    /// the local name of the child element
+    pub name: &'a str,
+    /// whether a missing child element is a parse error
+    pub required: bool,
+    /// parses the child element, which is the next element in the stack, and stores its value
+    pub parse: Box<FnMut(&mut S) -> Result<(), XmlParseError> + 'a>,
+}
+
+impl<'a, S: 'a> Field<'a, S> {
+    /// a field which has to be present
+    pub fn required<F>(name: &'a str, parse: F) -> Field<'a, S>
+        where F: FnMut(&mut S) -> Result<(), XmlParseError> + 'a
+    {
+        Field { name: name, required: true, parse: Box::new(parse) }
+    }
+
+    /// a field which may be missing
+    pub fn optional<F>(name: &'a str, parse: F) -> Field<'a, S>
+        where F: FnMut(&mut S) -> Result<(), XmlParseError> + 'a
+    {
+        Field { name: name, required: false, parse: Box::new(parse) }
+    }
+}
+
+/// consume the element named `parent`, passing its child elements, in any order, to the
+/// `parse` function of the field with the same name, or throw a parse error
+///
+/// Unknown child elements are skipped. Once the element has been consumed, the first required
+/// field which wasn't present is reported as missing.
+pub fn deserialize_struct<S: Peek + Next>(stack: &mut S,
+                                          parent: &str,
+                                          fields: &mut [Field<S>])
+                                          -> Result<(), XmlParseError> {
+    try!(start_element(parent, stack));
+    let mut seen = vec![false; fields.len()];
+    loop {
+        match peek_name(stack) {
+            PeekedName::Start(name) => {
+                match fields.iter().position(|field| field.name == name) {
+                    Some(index) => {
+                        try!((fields[index].parse)(stack));
+                        seen[index] = true;
+                    }
+                    None => skip_tree(stack),
+                }
+            }
+            PeekedName::Other(_) => {
+                if let Some(&Ok(XmlEvent::EndElement { .. })) = stack.peek() {
+                    break;
+                }
+                stack.next();
+            }
+            PeekedName::Error(e) => {
+                return Err(XmlParseError::new(&format!("Invalid XML document: {}", e)))
+            }
+            PeekedName::End => {
+                return Err(XmlParseError::new(&format!("Expected EndElement {} got end of document",
+                                                       parent)))
+            }
+        }
+    }
+    try!(end_element(parent, stack));
+
+    match fields.iter().zip(&seen).find(|&(field, seen)| field.required && !*seen) {
+        Some((field, _)) => {
+            Err(XmlParseError::new(&format!("Missing field {} in {}", field.name, parent)))
+        }
+        None => Ok(()),
+    }
+}
+
+/// return the pairs of a query string like `a=b&c=d` in the text of a field with the right
+/// name, URL-decoded, or throw a parse error
+///
+/// A pair without `=` has an empty value, `+` is decoded as a space.
+pub fn parse_query_text<T: Peek + Next>(name: &str,
+                                        stack: &mut T)
+                                        -> Result<HashMap<String, String>, XmlParseError> {
+    let text = try!(string_field(name, stack));
+    let mut pairs = HashMap::new();
+    for pair in text.split('&').filter(|pair| !pair.is_empty()) {
+        let mut parts = pair.splitn(2, '=');
+        let key = try!(query_decode(parts.next().unwrap_or("")));
+        let value = try!(query_decode(parts.next().unwrap_or("")));
+        pairs.insert(key, value);
+    }
+    Ok(pairs)
+}
+
+/// URL-decode a query string component, rejecting malformed percent-encoding
+fn query_decode(component: &str) -> Result<String, XmlParseError> {
+    let bytes = component.as_bytes();
+    for (i, &byte) in bytes.iter().enumerate() {
+        let escaped = bytes.get(i + 1..i + 3);
+        if byte == b'%' && !escaped.map_or(false, |hex| hex.iter().all(u8::is_ascii_hexdigit)) {
+            return Err(XmlParseError::new(&format!("Malformed percent-encoding in {:?}", component)));
+        }
+    }
+    let component = component.replace('+', " ");
+    percent_decode(component.as_bytes())
+        .decode_utf8()
+        .map(|decoded| decoded.into_owned())
+        .map_err(|_| XmlParseError::new(&format!("Invalid UTF-8 in {:?}", component)))
+}
+
+/// return a timestamp field with the right name or throw a parse error
+///
+/// AWS returns timestamps either as ISO 8601 (`2016-01-22T04:58:12.123Z`) or as
+/// epoch seconds (`1453438692.123`), the format is detected by content.
+pub fn timestamp_field<T: Peek + Next>(name: &str, stack: &mut T) -> Result<Tm, XmlParseError> {
+    let value = try!(string_field(name, stack));
+    parse_timestamp(&value)
+}
+
+fn parse_timestamp(value: &str) -> Result<Tm, XmlParseError> {
+    if let Some(epoch) = parse_epoch_seconds(value) {
+        return Ok(time::at_utc(epoch));
+    }
+    time::strptime(value, "%Y-%m-%dT%H:%M:%S.%f%z")
+        .or_else(|_| time::strptime(value, "%Y-%m-%dT%H:%M:%S%z"))
+        .map_err(|_| XmlParseError::new(&format!("Invalid timestamp {:?}", value)))
+}
+
+/// parse integer or fractional epoch seconds without going through a float,
+/// so sub-second precision is kept exactly
+fn parse_epoch_seconds(value: &str) -> Option<Timespec> {
+    let mut parts = value.splitn(2, '.');
+    let seconds = match parts.next() {
+        Some(s) if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) => s.parse::<i64>().ok(),
+        _ => None,
+    };
+    let nanoseconds = match parts.next() {
+        None => Some(0),
+        Some(f) if !f.is_empty() && f.len() <= 9 && f.bytes().all(|b| b.is_ascii_digit()) => {
+            format!("{:0<9}", f).parse::<i32>().ok()
+        }
+        Some(_) => None,
+    };
+    match (seconds, nanoseconds) {
+        (Some(seconds), Some(nanoseconds)) => Some(Timespec::new(seconds, nanoseconds)),
+        _ => None,
+    }
+}
+
+/// return a duration field with the right name or throw a parse error
+///
+/// The duration is either given as integer seconds (`5400`) or as an ISO 8601 duration
+/// (`PT1H30M`) of weeks, days, hours, minutes and seconds, the latter possibly fractional.
+/// Years and months are rejected, as their length isn't fixed.
+pub fn duration_field<T: Peek + Next>(name: &str,
+                                      stack: &mut T)
+                                      -> Result<Duration, XmlParseError> {
+    let value = try!(string_field(name, stack));
+    let value = value.trim();
+    let duration = if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+        value.parse::<u64>().ok().map(Duration::from_secs)
+    } else {
+        parse_iso8601_duration(value)
+    };
+    duration.ok_or_else(|| XmlParseError::new(&format!("Invalid duration {:?} in {}", value, name)))
+}
+
+/// parse an ISO 8601 duration like `P1DT2H` or `PT0.5S`, see `duration_field`
+fn parse_iso8601_duration(value: &str) -> Option<Duration> {
+    if !value.starts_with('P') || value.len() == 1 {
+        return None;
+    }
+    let (date, time) = match value[1..].find('T') {
+        Some(index) => (&value[1..index + 1], Some(&value[index + 2..])),
+        None => (&value[1..], None),
+    };
+    let date = duration_components(date, &[('W', 7 * 24 * 3600), ('D', 24 * 3600)]);
+    let time = match time {
+        Some("") => None,
+        Some(time) => duration_components(time, &[('H', 3600), ('M', 60), ('S', 1)]),
+        None => Some(Duration::from_secs(0)),
+    };
+    match (date, time) {
+        (Some(date), Some(time)) => date.checked_add(time),
+        _ => None,
+    }
+}
+
+/// sum up the components of one part of an ISO 8601 duration, whose designators have to
+/// appear in the order of `units`, each at most once
+fn duration_components(part: &str, units: &[(char, u64)]) -> Option<Duration> {
+    let mut duration = Duration::from_secs(0);
+    let mut rest = part;
+    let mut next_unit = 0;
+    while !rest.is_empty() {
+        let designator = rest.char_indices().find(|&(_, c)| !c.is_ascii_digit() && c != '.');
+        let (end, designator) = match designator {
+            Some(designator) => designator,
+            None => return None,
+        };
+        let index = match units[next_unit..].iter().position(|&(unit, _)| unit == designator) {
+            Some(position) => next_unit + position,
+            None => return None,
+        };
+        let number = &rest[..end];
+        let amount = if designator == 'S' {
+            parse_epoch_seconds(number)
+                .map(|seconds| Duration::new(seconds.sec as u64, seconds.nsec as u32))
+        } else {
+            number.parse::<u64>()
+                .ok()
+                .and_then(|number| number.checked_mul(units[index].1))
+                .map(Duration::from_secs)
+        };
+        duration = match amount.and_then(|amount| duration.checked_add(amount)) {
+            Some(duration) => duration,
+            None => return None,
+        };
+        next_unit = index + 1;
+        rest = &rest[end + designator.len_utf8()..];
+    }
+    Some(duration)
+}
+
 /// return some XML Characters
+///
+/// Text split into several events, e.g. around entity references with a parser not
+/// coalescing characters, or around CDATA sections, is joined into a single string.
 pub fn characters<T: Peek + Next>(stack: &mut T) -> Result<String, XmlParseError> {
     {
         // Lexical lifetime
@@ -91,11 +712,23 @@ pub fn characters<T: Peek + Next>(stack: &mut T) -> Result<String, XmlParseError
             return Ok("".to_string());
         }
     }
-    if let Some(Ok(XmlEvent::Characters(data))) = stack.next() {
-        Ok(data.to_string())
-    } else {
-        Err(XmlParseError::new("Expected characters"))
+    let mut data = match stack.next() {
+        Some(Ok(XmlEvent::Characters(data))) |
+        Some(Ok(XmlEvent::CData(data))) |
+        Some(Ok(XmlEvent::Whitespace(data))) => data,
+        _ => return Err(XmlParseError::new("Expected characters")),
+    };
+    // whitespace-only pieces, like the space in `<![CDATA[&]]> <![CDATA[&]]>`, are part of the text
+    loop {
+        match stack.peek_text() {
+            Some(&Ok(XmlEvent::Characters(ref more))) |
+            Some(&Ok(XmlEvent::CData(ref more))) |
+            Some(&Ok(XmlEvent::Whitespace(ref more))) => data.push_str(more),
+            _ => break,
+        }
+        stack.next_text();
     }
+    Ok(data)
 }
 
 /// get the name of the current element in the stack.  throw a parse error if it's not a `StartElement`
@@ -108,6 +741,33 @@ pub fn peek_at_name<T: Peek + Next>(stack: &mut T) -> Result<String, XmlParseErr
     }
 }
 
+/// what `peek_name` found at the current position of the XML stack
+#[derive(Debug, PartialEq)]
+pub enum PeekedName {
+    /// a `StartElement` with the given local name
+    Start(String),
+    /// any other well-formed event, e.g. an `EndElement` or `Characters`, with a
+    /// description of the event for diagnostics
+    Other(String),
+    /// the stack is exhausted after a well-formed document
+    End,
+    /// the parser failed, usually because the body is truncated or malformed
+    Error(String),
+}
+
+/// get the name of the current element in the stack, distinguishing a parser error
+/// (truncated or malformed input) from a cleanly ended stream
+pub fn peek_name<T: Peek + Next>(stack: &mut T) -> PeekedName {
+    match stack.peek() {
+        Some(&Ok(XmlEvent::StartElement { ref name, .. })) => {
+            PeekedName::Start(name.local_name.to_string())
+        }
+        Some(&Ok(ref event)) => PeekedName::Other(format!("{:?}", event)),
+        Some(&Err(ref e)) => PeekedName::Error(e.to_string()),
+        None => PeekedName::End,
+    }
+}
+
 /// consume a `StartElement` with a specific name or throw an `XmlParseError`
 pub fn start_element<T: Peek + Next>(element_name: &str,
                                      stack: &mut T)
@@ -131,6 +791,18 @@ pub fn start_element<T: Peek + Next>(element_name: &str,
     }
 }
 
+/// consume a `StartElement` with a specific name and return its `xsi:type` attribute
+/// (matched by local name) along with all of its attributes, or throw an `XmlParseError`
+///
+/// Used for polymorphic elements such as S3's `<Grantee xsi:type="CanonicalUser">`.
+pub fn start_element_with_type<T: Peek + Next>(element_name: &str,
+                                               stack: &mut T)
+                                               -> Result<(Option<String>, HashMap<String, String>), XmlParseError> {
+    let attributes = try!(start_element(element_name, stack));
+    let element_type = attributes.get("type").cloned();
+    Ok((element_type, attributes))
+}
+
 /// consume an `EndElement` with a specific name or throw an `XmlParseError`
 pub fn end_element<T: Peek + Next>(element_name: &str, stack: &mut T) -> Result<(), XmlParseError> {
     let next = stack.next();
@@ -147,6 +819,28 @@ pub fn end_element<T: Peek + Next>(element_name: &str, stack: &mut T) -> Result<
     }
 }
 
+/// consume an `EndElement` with a specific name, nested in the element named `parent`, or
+/// throw an `XmlParseError` naming both, to locate the error in deeply nested documents
+pub fn end_element_in<T: Peek + Next>(parent: &str,
+                                      element_name: &str,
+                                      stack: &mut T)
+                                      -> Result<(), XmlParseError> {
+    let got = match stack.next() {
+        Some(Ok(XmlEvent::EndElement { ref name })) if name.local_name == element_name => {
+            return Ok(())
+        }
+        Some(Ok(XmlEvent::EndElement { name })) => format!("</{}>", name.local_name),
+        Some(Ok(XmlEvent::StartElement { name, .. })) => format!("<{}>", name.local_name),
+        Some(Ok(event)) => format!("{:?}", event),
+        Some(Err(e)) => format!("invalid XML: {}", e),
+        None => "end of document".to_owned(),
+    };
+    Err(XmlParseError::new(&format!("Expected </{}> inside <{}>, got {}",
+                                    element_name,
+                                    parent,
+                                    got)))
+}
+
 /// skip a tag and all its children
 pub fn skip_tree<T: Peek + Next>(stack: &mut T) {
 
@@ -185,10 +879,58 @@ pub fn find_start_element<T: Peek + Next>(stack: &mut T) {
     }
 }
 
+/// consume the `StartDocument` event, which must be the next event in the stack
+///
+/// The parser emits `StartDocument` at the start of every document, even if it lacks an
+/// explicit `<?xml ...?>` declaration, so this fails if the stack was already advanced.
+/// `XmlResponse` skips the event by default, see `set_skip_document_events`.
+pub fn consume_declaration<T: Peek + Next>(stack: &mut T) -> Result<(), XmlParseError> {
+    match stack.next() {
+        Some(Ok(XmlEvent::StartDocument { .. })) => Ok(()),
+        Some(Err(e)) => Err(XmlParseError::new(&format!("Invalid XML document: {}", e))),
+        next => Err(XmlParseError::new(&format!("Expected StartDocument got {:?}", next))),
+    }
+}
+
+/// skip the XML declaration and anything else preceding the root element, such as
+/// whitespace, comments or processing instructions
+///
+/// Unlike `find_start_element`, parser errors and a stream without any element
+/// (e.g. an empty body) are reported.
+pub fn skip_preamble<T: Peek + Next>(stack: &mut T) -> Result<(), XmlParseError> {
+    loop {
+        match peek_name(stack) {
+            PeekedName::Start(_) => return Ok(()),
+            PeekedName::Other(_) => {
+                stack.next();
+            }
+            PeekedName::Error(e) => {
+                return Err(XmlParseError::new(&format!("Invalid XML document: {}", e)))
+            }
+            PeekedName::End => return Err(XmlParseError::new("No root element found")),
+        }
+    }
+}
+
+/// consume all remaining events, to make sure the rest of the document is well-formed
+/// after the needed parts have been deserialized
+///
+/// Stops at `EndDocument` or when the stack is exhausted, and reports the first parser
+/// error, e.g. for a truncated document or trailing garbage.
+pub fn drain_to_end<T: Peek + Next>(stack: &mut T) -> Result<(), XmlParseError> {
+    loop {
+        match stack.next() {
+            Some(Ok(XmlEvent::EndDocument)) | None => return Ok(()),
+            Some(Ok(_)) => (),
+            Some(Err(e)) => return Err(XmlParseError::new(&format!("Invalid XML document: {}", e))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use xml::reader::EventReader;
+    use xml::reader::{EventReader, ParserConfig};
     use std::io::Read;
     use std::fs::File;
 
@@ -198,8 +940,7 @@ mod tests {
         let mut body = String::new();
         let _size = file.read_to_string(&mut body);
         let my_parser = EventReader::new(body.as_bytes());
-        let my_stack = my_parser.into_iter().peekable();
-        let mut reader = XmlResponse::new(my_stack);
+        let mut reader = XmlResponse::new(my_parser);
 
         loop {
             reader.next();
@@ -220,11 +961,9 @@ mod tests {
         let mut body = String::new();
         let _size = file.read_to_string(&mut body);
         let my_parser = EventReader::new(body.as_bytes());
-        let my_stack = my_parser.into_iter().peekable();
-        let mut reader = XmlResponse::new(my_stack);
+        let mut reader = XmlResponse::new(my_parser);
 
-        // skip two leading fields since we ignore them (xml declaration, return type declaration)
-        reader.next();
+        // the xml declaration is skipped, the return type declaration is ignored
         reader.next();
 
         match start_element("ListQueuesResult", &mut reader) {
@@ -239,11 +978,9 @@ mod tests {
         let mut body = String::new();
         let _size = file.read_to_string(&mut body);
         let my_parser = EventReader::new(body.as_bytes());
-        let my_stack = my_parser.into_iter().peekable();
-        let mut reader = XmlResponse::new(my_stack);
+        let mut reader = XmlResponse::new(my_parser);
 
-        // skip two leading fields since we ignore them (xml declaration, return type declaration)
-        reader.next();
+        // the xml declaration is skipped, the return type declaration is ignored
         reader.next();
 
         reader.next(); // reader now at ListQueuesResult
@@ -254,17 +991,78 @@ mod tests {
                    "https://sqs.us-east-1.amazonaws.com/347452556413/testqueue")
     }
 
+    #[test]
+    fn string_list_field_happy_path() {
+        fn queue_urls(path: &str) -> Vec<String> {
+            let mut file = File::open(path).unwrap();
+            let mut body = String::new();
+            let _size = file.read_to_string(&mut body);
+            let my_parser = EventReader::new(body.as_bytes());
+            let mut reader = XmlResponse::new(my_parser);
+            find_start_element(&mut reader);
+            start_element("ListQueuesResponse", &mut reader).unwrap();
+            start_element("ListQueuesResult", &mut reader).unwrap();
+
+            let urls = string_list_field("QueueUrl", &mut reader).unwrap();
+            end_element("ListQueuesResult", &mut reader).unwrap();
+            urls
+        }
+
+        assert_eq!(queue_urls("test_resources/list_queues_with_queue.xml"),
+                   vec!["https://sqs.us-east-1.amazonaws.com/347452556413/testqueue"]);
+        assert_eq!(queue_urls("test_resources/list_queues_with_queues.xml"),
+                   vec!["https://sqs.us-east-1.amazonaws.com/347452556413/testqueue",
+                        "https://sqs.us-east-1.amazonaws.com/347452556413/otherqueue"]);
+    }
+
+    #[test]
+    fn position_advances() {
+        let body = b"<ListQueuesResult>\n  <QueueUrl>a</QueueUrl>\n  <QueueUrl>b</QueueUrl>\n</ListQueuesResult>";
+        let mut reader = XmlResponse::new(EventReader::new(&body[..]));
+        find_start_element(&mut reader);
+        start_element("ListQueuesResult", &mut reader).unwrap();
+        assert_eq!(reader.position().row, 0);
+
+        string_field("QueueUrl", &mut reader).unwrap();
+        assert_eq!(reader.position().row, 1);
+        // peeking doesn't consume the next event
+        assert_eq!(peek_at_name(&mut reader).unwrap(), "QueueUrl");
+        assert_eq!(reader.position().row, 1);
+
+        string_field("QueueUrl", &mut reader).unwrap();
+        assert_eq!(reader.position().row, 2);
+        end_element("ListQueuesResult", &mut reader).unwrap();
+        assert_eq!(reader.position().row, 3);
+    }
+
+    #[test]
+    fn optional_wrapped_list_field_cases() {
+        fn parse(body: &str) -> Option<Vec<String>> {
+            let parser = EventReader::new(body.as_bytes());
+            let mut reader = XmlResponse::new(parser);
+            find_start_element(&mut reader);
+            start_element("Queue", &mut reader).unwrap();
+            let tags = optional_wrapped_list_field("Tags", "Tag", &mut reader, string_field).unwrap();
+            assert_eq!(peek_at_name(&mut reader).unwrap(), "Name");
+            tags
+        }
+
+        assert_eq!(parse("<Queue><Name>q</Name></Queue>"), None);
+        assert_eq!(parse("<Queue><Tags/><Name>q</Name></Queue>"), Some(vec![]));
+        assert_eq!(parse("<Queue><Tags></Tags><Name>q</Name></Queue>"), Some(vec![]));
+        assert_eq!(parse("<Queue><Tags><Tag>a</Tag><Tag>b</Tag></Tags><Name>q</Name></Queue>"),
+                   Some(vec!["a".to_owned(), "b".to_owned()]));
+    }
+
     #[test]
     fn end_element_happy_path() {
         let mut file = File::open("test_resources/list_queues_with_queue.xml").unwrap();
         let mut body = String::new();
         let _size = file.read_to_string(&mut body);
         let my_parser = EventReader::new(body.as_bytes());
-        let my_stack = my_parser.into_iter().peekable();
-        let mut reader = XmlResponse::new(my_stack);
+        let mut reader = XmlResponse::new(my_parser);
 
-        // skip two leading fields since we ignore them (xml declaration, return type declaration)
-        reader.next();
+        // the xml declaration is skipped, the return type declaration is ignored
         reader.next();
 
 
@@ -281,12 +1079,556 @@ mod tests {
         }
     }
 
+    #[test]
+    fn end_element_in_names_parent() {
+        let body = b"<Bar><Foo><Id>1</Id><Extra/></Foo></Bar>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        start_element("Bar", &mut reader).unwrap();
+        start_element("Foo", &mut reader).unwrap();
+        assert_eq!(string_field("Id", &mut reader).unwrap(), "1");
+        assert_eq!(end_element_in("Bar", "Foo", &mut reader),
+                   Err(XmlParseError::new("Expected </Foo> inside <Bar>, got <Extra>")));
+        assert_eq!(end_element_in("Bar", "Foo", &mut reader),
+                   Err(XmlParseError::new("Expected </Foo> inside <Bar>, got </Extra>")));
+        end_element_in("Bar", "Foo", &mut reader).unwrap();
+        end_element("Bar", &mut reader).unwrap();
+        assert_eq!(end_element_in("Bar", "Foo", &mut reader),
+                   Err(XmlParseError::new("Expected </Foo> inside <Bar>, got end of document")));
+    }
+
+    #[test]
+    fn peek_name_distinguishes_truncated_from_ended() {
+        let body = b"<Foo><Bar/></Foo>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        find_start_element(&mut reader);
+        assert_eq!(peek_name(&mut reader), PeekedName::Start("Foo".to_owned()));
+        skip_tree(&mut reader);
+        assert_eq!(peek_name(&mut reader), PeekedName::End);
+
+        let body = b"<Foo><Bar/>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        find_start_element(&mut reader);
+        reader.next(); // Foo
+        assert_eq!(peek_name(&mut reader), PeekedName::Start("Bar".to_owned()));
+        reader.next();
+        reader.next();
+        match peek_name(&mut reader) {
+            PeekedName::Error(_) => (),
+            other => panic!("expected parser error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn timestamp_field_formats() {
+        fn parse(value: &str) -> Timespec {
+            let body = format!("<Timestamp>{}</Timestamp>", value);
+            let parser = EventReader::new(body.as_bytes());
+            let mut reader = XmlResponse::new(parser);
+            find_start_element(&mut reader);
+            timestamp_field("Timestamp", &mut reader).unwrap().to_timespec()
+        }
+
+        assert_eq!(parse("1453438692"), Timespec::new(1453438692, 0));
+        assert_eq!(parse("1453438692.5"), Timespec::new(1453438692, 500_000_000));
+        assert_eq!(parse("1453438692.123"), Timespec::new(1453438692, 123_000_000));
+        assert_eq!(parse("2016-01-22T04:58:12Z"), Timespec::new(1453438692, 0));
+        assert_eq!(parse("2016-01-22T04:58:12.500Z"), Timespec::new(1453438692, 500_000_000));
+    }
+
+    #[test]
+    fn duration_field_formats() {
+        fn parse(value: &str) -> Result<Duration, XmlParseError> {
+            let body = format!("<Duration>{}</Duration>", value);
+            let parser = EventReader::new(body.as_bytes());
+            let mut reader = XmlResponse::new(parser);
+            find_start_element(&mut reader);
+            duration_field("Duration", &mut reader)
+        }
+
+        assert_eq!(parse("5400").unwrap(), Duration::from_secs(5400));
+        assert_eq!(parse(" 0 ").unwrap(), Duration::from_secs(0));
+
+        assert_eq!(parse("PT1H30M").unwrap(), Duration::from_secs(5400));
+        assert_eq!(parse("PT45S").unwrap(), Duration::from_secs(45));
+        assert_eq!(parse("PT0.5S").unwrap(), Duration::from_millis(500));
+        assert_eq!(parse("P1DT12H").unwrap(), Duration::from_secs(36 * 3600));
+        assert_eq!(parse("P2W").unwrap(), Duration::from_secs(14 * 24 * 3600));
+    }
+
+    #[test]
+    fn duration_field_invalid() {
+        fn parse(value: &str) -> Result<Duration, XmlParseError> {
+            let body = format!("<Duration>{}</Duration>", value);
+            let parser = EventReader::new(body.as_bytes());
+            let mut reader = XmlResponse::new(parser);
+            find_start_element(&mut reader);
+            duration_field("Duration", &mut reader)
+        }
+
+        assert_eq!(parse("PT1H30").unwrap_err(),
+                   XmlParseError::new("Invalid duration \"PT1H30\" in Duration"));
+        for value in &["", "-5", "1.5", "P", "PT", "P1Y", "P1M", "PT30M1H", "PT1.5H", "1h"] {
+            assert!(parse(value).is_err(), "{:?} was accepted", value);
+        }
+    }
+
+    #[test]
+    fn optional_string_field_nil() {
+        fn parse(body: &str) -> Option<String> {
+            let parser = EventReader::new(body.as_bytes());
+            let mut reader = XmlResponse::new(parser);
+            find_start_element(&mut reader);
+            optional_string_field("Foo", &mut reader).unwrap()
+        }
+
+        let xsi = "xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\"";
+        assert_eq!(parse(&format!("<Foo {} xsi:nil=\"true\"/>", xsi)), None);
+        assert_eq!(parse(&format!("<Foo {} xsi:nil=\"false\">bar</Foo>", xsi)), Some("bar".to_owned()));
+        assert_eq!(parse("<Foo></Foo>"), Some("".to_owned()));
+        assert_eq!(parse("<Foo>bar</Foo>"), Some("bar".to_owned()));
+    }
+
+    #[test]
+    fn parse_query_text_pairs() {
+        fn parse(body: &str) -> Result<HashMap<String, String>, XmlParseError> {
+            let parser = EventReader::new(body.as_bytes());
+            let mut reader = XmlResponse::new(parser);
+            find_start_element(&mut reader);
+            parse_query_text("Metadata", &mut reader)
+        }
+
+        let pairs = parse("<Metadata>owner=team%20a&amp;path=%2Fdata%2Fx+y</Metadata>").unwrap();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs["owner"], "team a");
+        assert_eq!(pairs["path"], "/data/x y");
+
+        assert!(parse("<Metadata></Metadata>").unwrap().is_empty());
+        assert_eq!(parse("<Metadata>owner=team%2&amp;path=x</Metadata>").unwrap_err(),
+                   XmlParseError::new("Malformed percent-encoding in \"team%2\""));
+    }
+
+    #[test]
+    fn timestamp_field_invalid() {
+        let body = b"<Timestamp>yesterday</Timestamp>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        find_start_element(&mut reader);
+        assert!(timestamp_field("Timestamp", &mut reader).is_err());
+    }
+
+    #[test]
+    fn start_element_with_type_typed_grantee() {
+        let body = br#"<Grantee xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="CanonicalUser">
+                           <ID>75aa57f09aa0c8caeab4f8c24e99d10f8e7faeebf76c078efc7c6caea54ba06a</ID>
+                       </Grantee>"#;
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        find_start_element(&mut reader);
+
+        let (element_type, attributes) = start_element_with_type("Grantee", &mut reader).unwrap();
+        assert_eq!(element_type, Some("CanonicalUser".to_owned()));
+        assert_eq!(attributes.get("type").map(|s| &s[..]), Some("CanonicalUser"));
+        assert_eq!(peek_at_name(&mut reader).unwrap(), "ID");
+    }
+
+    #[test]
+    fn start_element_with_type_untyped() {
+        let body = b"<Owner><ID>abc</ID></Owner>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        find_start_element(&mut reader);
+
+        let (element_type, attributes) = start_element_with_type("Owner", &mut reader).unwrap();
+        assert_eq!(element_type, None);
+        assert!(attributes.is_empty());
+    }
+
+    #[test]
+    fn skip_preamble_leading_newlines() {
+        let body = b"\n\n  \n<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!-- generated -->\n<ListBucketResult/>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        skip_preamble(&mut reader).unwrap();
+        assert_eq!(peek_at_name(&mut reader).unwrap(), "ListBucketResult");
+
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        find_start_element(&mut reader);
+        assert_eq!(peek_at_name(&mut reader).unwrap(), "ListBucketResult");
+    }
+
+    #[test]
+    fn consume_declaration_at_start() {
+        let body = b"<?xml version=\"1.0\" encoding=\"UTF-8\"?><ListBucketResult/>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        reader.set_skip_document_events(false);
+        consume_declaration(&mut reader).unwrap();
+        assert_eq!(peek_at_name(&mut reader).unwrap(), "ListBucketResult");
+
+        // the stack is at the root element, not at the declaration
+        let XmlParseError(message) = consume_declaration(&mut reader).unwrap_err();
+        assert!(message.starts_with("Expected StartDocument got Some(Ok(StartElement(ListBucketResult"),
+                "{}", message);
+    }
+
+    #[test]
+    fn document_events_skipped() {
+        let body = b"<?xml version=\"1.0\"?>\n<?generator name=\"rusoto\"?>\n<!-- generated -->\n\
+                     <Foo><!-- note --><Bar>1</Bar></Foo>\n<!-- trailer -->";
+        let mut reader = XmlResponse::new(EventReader::new(&body[..]));
+        start_element("Foo", &mut reader).unwrap();
+        assert_eq!(string_field("Bar", &mut reader).unwrap(), "1");
+        end_element("Foo", &mut reader).unwrap();
+        assert_eq!(peek_name(&mut reader), PeekedName::End);
+        assert!(reader.next().is_none());
+
+        let mut reader = OwnedXmlResponse::new(body.to_vec());
+        start_element("Foo", &mut reader).unwrap();
+
+        let mut reader = XmlResponse::new(EventReader::new(&body[..]));
+        reader.set_skip_document_events(false);
+        match reader.next() {
+            Some(Ok(XmlEvent::StartDocument { .. })) => (),
+            other => panic!("expected StartDocument, got {:?}", other),
+        }
+        match reader.next() {
+            Some(Ok(XmlEvent::ProcessingInstruction { ref name, .. })) => {
+                assert_eq!(name, "generator")
+            }
+            other => panic!("expected ProcessingInstruction, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn skip_preamble_empty_body() {
+        let parser = EventReader::new(&b"\n\n"[..]);
+        let mut reader = XmlResponse::new(parser);
+        assert!(skip_preamble(&mut reader).is_err());
+    }
+
+    #[test]
+    fn owned_xml_response_from_vec() {
+        fn open(path: &str) -> OwnedXmlResponse {
+            let mut body = Vec::new();
+            File::open(path).unwrap().read_to_end(&mut body).unwrap();
+            OwnedXmlResponse::new(body)
+        }
+
+        let mut reader = open("test_resources/list_queues_with_queue.xml");
+        find_start_element(&mut reader);
+        start_element("ListQueuesResponse", &mut reader).unwrap();
+        start_element("ListQueuesResult", &mut reader).unwrap();
+        assert_eq!(string_field("QueueUrl", &mut reader).unwrap(),
+                   "https://sqs.us-east-1.amazonaws.com/347452556413/testqueue");
+        end_element("ListQueuesResult", &mut reader).unwrap();
+    }
+
+    #[test]
+    fn peek_name_describes_other_events() {
+        let body = b"<Foo>text</Foo>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        find_start_element(&mut reader);
+        reader.next();
+        match peek_name(&mut reader) {
+            PeekedName::Other(description) => assert!(description.contains("text"), "{}", description),
+            other => panic!("expected other event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn characters_whitespace_only() {
+        let body = b"<Value>   </Value>";
+
+        let mut reader = XmlResponse::new(EventReader::new(&body[..]));
+        find_start_element(&mut reader);
+        start_element("Value", &mut reader).unwrap();
+        assert_eq!(characters(&mut reader).unwrap(), "");
+        end_element("Value", &mut reader).unwrap();
+
+        let mut reader = XmlResponse::new(EventReader::new(&body[..]));
+        reader.set_skip_whitespace(false);
+        find_start_element(&mut reader);
+        start_element("Value", &mut reader).unwrap();
+        assert_eq!(characters(&mut reader).unwrap(), "   ");
+        end_element("Value", &mut reader).unwrap();
+
+        let mut reader = XmlResponse::new(EventReader::new(&body[..]));
+        reader.set_skip_whitespace(false);
+        find_start_element(&mut reader);
+        assert_eq!(string_field("Value", &mut reader).unwrap(), "   ");
+    }
+
+    #[test]
+    fn characters_split_by_entity_references() {
+        let body = b"<Result><Value>a &amp; b</Value><Value>&lt;x&gt;<![CDATA[ & ]]>&#x79;</Value></Result>";
+        let config = ParserConfig::new().coalesce_characters(false);
+        let mut reader = XmlResponse::new(EventReader::new_with_config(&body[..], config));
+        start_element("Result", &mut reader).unwrap();
+        assert_eq!(string_field("Value", &mut reader).unwrap(), "a & b");
+        assert_eq!(string_field("Value", &mut reader).unwrap(), "<x> & y");
+        end_element("Result", &mut reader).unwrap();
+    }
+
+    #[test]
+    fn characters_adjacent_entity_references() {
+        let body = b"<Result><Value>x &amp; &amp; y</Value>\
+                     <Value><![CDATA[&]]> <![CDATA[&]]></Value></Result>";
+
+        let mut reader = XmlResponse::new(EventReader::new(&body[..]));
+        start_element("Result", &mut reader).unwrap();
+        assert_eq!(string_field("Value", &mut reader).unwrap(), "x & & y");
+        assert_eq!(string_field("Value", &mut reader).unwrap(), "& &");
+        end_element("Result", &mut reader).unwrap();
+
+        let mut reader = XmlResponse::new(EventReader::new(&body[..]));
+        start_element("Result", &mut reader).unwrap();
+        assert_eq!(string_field("Value", &mut reader).unwrap(), "x & & y");
+        assert_eq!(string_field("Value", &mut reader).unwrap(), "& &");
+
+        let mut reader = OwnedXmlResponse::new(body.to_vec());
+        start_element("Result", &mut reader).unwrap();
+        assert_eq!(string_field("Value", &mut reader).unwrap(), "x & & y");
+        assert_eq!(string_field("Value", &mut reader).unwrap(), "& &");
+    }
+
+    #[test]
+    fn blank_characters_between_elements() {
+        let body = b"<Result>\n  <A>1</A>\n  <B>  </B>\n</Result>";
+        let config = ParserConfig::new().whitespace_to_characters(true);
+
+        let mut reader = XmlResponse::new(EventReader::new_with_config(&body[..], config.clone()));
+        start_element("Result", &mut reader).unwrap();
+        assert_eq!(peek_at_name(&mut reader).unwrap(), "A");
+        assert_eq!(string_field("A", &mut reader).unwrap(), "1");
+        assert_eq!(peek_at_name(&mut reader).unwrap(), "B");
+        assert_eq!(string_field("B", &mut reader).unwrap(), "");
+        end_element("Result", &mut reader).unwrap();
+
+        let mut reader = XmlResponse::new(EventReader::new_with_config(&body[..], config));
+        reader.set_skip_blank_text(false);
+        start_element("Result", &mut reader).unwrap();
+        assert_eq!(peek_at_name(&mut reader).unwrap(), "");
+        assert_eq!(characters(&mut reader).unwrap(), "\n  ");
+    }
+
+    #[test]
+    fn deserialize_struct_any_order() {
+        fn parse(body: &str) -> Result<(String, Option<String>), XmlParseError> {
+            let parser = EventReader::new(body.as_bytes());
+            let mut reader = XmlResponse::new(parser);
+            let mut id = None;
+            let mut display_name = None;
+            {
+                let mut fields = [
+                    Field::required("ID", |stack: &mut XmlResponse| {
+                        id = Some(try!(string_field("ID", stack)));
+                        Ok(())
+                    }),
+                    Field::optional("DisplayName", |stack: &mut XmlResponse| {
+                        display_name = Some(try!(string_field("DisplayName", stack)));
+                        Ok(())
+                    }),
+                ];
+                try!(deserialize_struct(&mut reader, "Owner", &mut fields));
+            }
+            Ok((id.unwrap_or_default(), display_name))
+        }
+
+        assert_eq!(parse("<Owner><ID>1</ID><DisplayName>n</DisplayName></Owner>"),
+                   Ok(("1".to_owned(), Some("n".to_owned()))));
+        assert_eq!(parse("<Owner><DisplayName>n</DisplayName><Extra><ID>x</ID></Extra><ID>1</ID></Owner>"),
+                   Ok(("1".to_owned(), Some("n".to_owned()))));
+        assert_eq!(parse("<Owner><ID>1</ID></Owner>"), Ok(("1".to_owned(), None)));
+        assert_eq!(parse("<Owner><DisplayName>n</DisplayName></Owner>"),
+                   Err(XmlParseError::new("Missing field ID in Owner")));
+        assert_eq!(parse("<Owner/>"), Err(XmlParseError::new("Missing field ID in Owner")));
+    }
+
+    #[test]
+    fn text_or_struct_both_forms() {
+        fn parse(body: &str) -> Result<TextOrStruct<(String, String)>, XmlParseError> {
+            let parser = EventReader::new(body.as_bytes());
+            let mut reader = XmlResponse::new(parser);
+            text_or_struct("Principal", &mut reader, |stack| {
+                let kind = try!(string_field("Type", stack));
+                let id = try!(string_field("Id", stack));
+                Ok((kind, id))
+            })
+        }
+
+        assert_eq!(parse("<Principal>*</Principal>"), Ok(TextOrStruct::Text("*".to_owned())));
+        assert_eq!(parse("<Principal/>"), Ok(TextOrStruct::Text("".to_owned())));
+        assert_eq!(parse("<Principal>\n  <Type>AWS</Type>\n  <Id>123456789012</Id>\n</Principal>"),
+                   Ok(TextOrStruct::Struct(("AWS".to_owned(), "123456789012".to_owned()))));
+        assert!(parse("<Principal><Type>AWS</Type><Id>1</Id><Extra/></Principal>").is_err());
+    }
+
+    #[test]
+    fn presence_flag_present_and_absent() {
+        let body = b"<Vpc><IsDefault/><Encrypted>yes</Encrypted><VpcId>vpc-1</VpcId></Vpc>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        find_start_element(&mut reader);
+        start_element("Vpc", &mut reader).unwrap();
+
+        assert_eq!(presence_flag("IsDefault", &mut reader), Ok(true));
+        // content is ignored, only the presence matters
+        assert_eq!(presence_flag("Encrypted", &mut reader), Ok(true));
+        assert_eq!(presence_flag("Encrypted", &mut reader), Ok(false));
+        assert_eq!(string_field("VpcId", &mut reader).unwrap(), "vpc-1");
+        // at the end of the parent element
+        assert_eq!(presence_flag("IsDefault", &mut reader), Ok(false));
+        end_element("Vpc", &mut reader).unwrap();
+    }
+
+    #[test]
+    fn csv_field_splits_and_trims() {
+        let body = b"<Result><Caps>a, b ,c</Caps><Caps></Caps><Caps> </Caps><Caps>d,,e,</Caps></Result>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        find_start_element(&mut reader);
+        start_element("Result", &mut reader).unwrap();
+
+        assert_eq!(csv_field("Caps", &mut reader).unwrap(), vec!["a", "b", "c"]);
+        assert_eq!(csv_field("Caps", &mut reader).unwrap(), Vec::<String>::new());
+        assert_eq!(csv_field("Caps", &mut reader).unwrap(), Vec::<String>::new());
+        assert_eq!(csv_field("Caps", &mut reader).unwrap(), vec!["d", "e"]);
+        assert!(csv_field("Caps", &mut reader).is_err());
+    }
+
+    #[test]
+    fn bool_fields() {
+        fn parse(value: &str, lenient: bool) -> Result<bool, XmlParseError> {
+            let body = format!("<Flag>{}</Flag>", value);
+            let parser = EventReader::new(body.as_bytes());
+            let mut reader = XmlResponse::new(parser);
+            find_start_element(&mut reader);
+            if lenient {
+                lenient_bool_field("Flag", &mut reader)
+            } else {
+                bool_field("Flag", &mut reader)
+            }
+        }
+
+        for &(value, expected) in &[("true", true), ("false", false)] {
+            assert_eq!(parse(value, false), Ok(expected), "{}", value);
+            assert_eq!(parse(value, true), Ok(expected), "{}", value);
+        }
+        for &(value, expected) in &[("TRUE", true), ("False", false), ("yes", true), ("YES", true),
+                                    ("no", false), ("No", false), ("on", true), ("ON", true),
+                                    ("off", false), ("Off", false), (" yes ", true)] {
+            assert_eq!(parse(value, true), Ok(expected), "{}", value);
+            assert!(parse(value, false).is_err(), "{}", value);
+        }
+        assert_eq!(parse("maybe", true),
+                   Err(XmlParseError::new("Invalid boolean \"maybe\" in Flag")));
+        assert_eq!(parse("maybe", false),
+                   Err(XmlParseError::new("Invalid boolean \"maybe\" in Flag")));
+    }
+
+    #[test]
+    fn drain_to_end_complete_and_truncated() {
+        fn parse(body: &[u8]) -> Result<(), XmlParseError> {
+            let parser = EventReader::new(body);
+            let mut reader = XmlResponse::new(parser);
+            find_start_element(&mut reader);
+            start_element("Result", &mut reader).unwrap();
+            assert_eq!(string_field("A", &mut reader).unwrap(), "1");
+            drain_to_end(&mut reader)
+        }
+
+        assert_eq!(parse(b"<Result><A>1</A><B><C>2</C></B></Result>"), Ok(()));
+        let XmlParseError(message) = parse(b"<Result><A>1</A><B><C>2</C>").unwrap_err();
+        assert!(message.starts_with("Invalid XML document: "), "{}", message);
+    }
+
+    #[test]
+    fn parse_tagged_enum_by_name() {
+        #[derive(Debug, PartialEq)]
+        enum Condition {
+            Equals(String),
+            Exists(String),
+        }
+
+        fn condition<T: Peek + Next>(stack: &mut T) -> Result<Condition, XmlParseError> {
+            parse_tagged_enum(&["Equals", "Exists"], stack, |name, stack| match name {
+                "Equals" => string_field("Equals", stack).map(Condition::Equals),
+                _ => string_field("Exists", stack).map(Condition::Exists),
+            })
+        }
+
+        let body = b"<Conditions><Exists>a</Exists><Equals>b</Equals><Matches>c</Matches></Conditions>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        find_start_element(&mut reader);
+        start_element("Conditions", &mut reader).unwrap();
+
+        assert_eq!(condition(&mut reader), Ok(Condition::Exists("a".to_owned())));
+        assert_eq!(condition(&mut reader), Ok(Condition::Equals("b".to_owned())));
+        assert_eq!(condition(&mut reader),
+                   Err(XmlParseError::new("Unexpected element Matches, expected one of Equals, Exists")));
+        // nothing was consumed
+        assert_eq!(peek_at_name(&mut reader).unwrap(), "Matches");
+    }
+
+    #[test]
+    fn struct_field_nested() {
+        #[derive(Debug, PartialEq)]
+        struct Owner {
+            id: String,
+            display_name: String,
+        }
+
+        let body = b"<Bucket><Owner><ID>abc</ID><DisplayName>webfile</DisplayName></Owner>\
+                     <Name>quotes</Name></Bucket>";
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        find_start_element(&mut reader);
+
+        let (owner, name) = struct_field("Bucket", &mut reader, |stack| {
+            let owner = try!(struct_field("Owner", stack, |stack| {
+                Ok(Owner {
+                    id: try!(string_field("ID", stack)),
+                    display_name: try!(string_field("DisplayName", stack)),
+                })
+            }));
+            Ok((owner, try!(string_field("Name", stack))))
+        }).unwrap();
+        assert_eq!(owner, Owner { id: "abc".to_owned(), display_name: "webfile".to_owned() });
+        assert_eq!(name, "quotes");
+
+        // a body leaving content unread fails on the end tag
+        let parser = EventReader::new(&body[..]);
+        let mut reader = XmlResponse::new(parser);
+        find_start_element(&mut reader);
+        let result = struct_field("Bucket", &mut reader, |stack| {
+            struct_field("Owner", stack, |stack| string_field("ID", stack))
+        });
+        let XmlParseError(message) = result.unwrap_err();
+        assert!(message.starts_with("Expected EndElement Owner got "), "{}", message);
+    }
+
+    #[test]
+    fn xml_parse_error_eq() {
+        assert_eq!(XmlParseError::new("Expected characters"),
+                   XmlParseError("Expected characters".to_owned()));
+        assert_eq!(XmlParseError::new("ParseIntError").clone(),
+                   XmlParseError::from("x".parse::<i32>().unwrap_err()));
+        assert_ne!(XmlParseError::new("Expected characters"),
+                   XmlParseError::new("Expected StartElement"));
+        assert_ne!(XmlParseError::new("ParseIntError"), XmlParseError::new("parseinterror"));
+    }
+
     #[test]
     fn test_find_start_element() {
         let body = include_bytes!("../test_resources/list_queues_with_queue.xml");
         let parser = EventReader::new(&body[..]);
-        let stack = parser.into_iter().peekable();
-        let mut reader = XmlResponse::new(stack);
+        let mut reader = XmlResponse::new(parser);
 
         // skip first two elements
         find_start_element(&mut reader);
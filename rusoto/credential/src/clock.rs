@@ -0,0 +1,21 @@
+//! Provides the current time to expiry decisions, so it can be controlled in tests.
+
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+
+/// A source of the current time, used to decide whether credentials are expired.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Get the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The `Clock` returning the system time, used unless another one is set.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
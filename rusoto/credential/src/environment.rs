@@ -11,6 +11,7 @@ use {AwsCredentials, CredentialsError, ProvideAwsCredentials, non_empty_env_var}
 const AWS_ACCESS_KEY_ID: &str = "AWS_ACCESS_KEY_ID";
 const AWS_SECRET_ACCESS_KEY: &str = "AWS_SECRET_ACCESS_KEY";
 const AWS_SESSION_TOKEN: &str = "AWS_SESSION_TOKEN";
+const AWS_SECURITY_TOKEN: &str = "AWS_SECURITY_TOKEN";
 const AWS_CREDENTIAL_EXPIRATION: &str = "AWS_CREDENTIAL_EXPIRATION";
 
 const E_NO_ACCESS_KEY_ID: &str = "No (or empty) AWS_ACCESS_KEY_ID in environment";
@@ -56,8 +57,16 @@ fn credentials_from_environment() -> Result<AwsCredentials, CredentialsError> {
             return Err(CredentialsError::new(E_NO_SECRET_ACCESS_KEY))
         }
     };
-    // Present when using temporary credentials, e.g. on Lambda with IAM roles
-    let token = non_empty_env_var(AWS_SESSION_TOKEN);
+    // Present when using temporary credentials, e.g. on Lambda with IAM roles.
+    // `AWS_SECURITY_TOKEN` is the deprecated name of `AWS_SESSION_TOKEN`, like
+    // `aws_security_token` in the credentials file.
+    let token = non_empty_env_var(AWS_SESSION_TOKEN).or_else(|| {
+        let token = non_empty_env_var(AWS_SECURITY_TOKEN);
+        if token.is_some() {
+            warn!("{} is deprecated, use {} instead", AWS_SECURITY_TOKEN, AWS_SESSION_TOKEN);
+        }
+        token
+    });
     // Mimik botocore's behavior, see https://github.com/boto/botocore/pull/1187.
     let expires_at = match non_empty_env_var(AWS_CREDENTIAL_EXPIRATION) {
         Some(val) => match DateTime::<Utc>::from_str(&val) {
@@ -78,7 +87,8 @@ fn credentials_from_environment() -> Result<AwsCredentials, CredentialsError> {
 mod tests {
     use std::env;
     use std::sync::{Mutex, MutexGuard};
-    use chrono::Utc;
+    use chrono::TimeZone;
+    use test_utils::{init_logger, log_messages};
     use super::*;
 
     // cargo runs tests in parallel, which leads to race conditions when changing
@@ -114,6 +124,32 @@ mod tests {
         assert_eq!(creds.token(), &Some("token".to_string()));
     }
 
+    #[test]
+    fn get_security_token_from_env() {
+        let _guard = lock(&ENV_MUTEX);
+        init_logger();
+        env::set_var(AWS_ACCESS_KEY_ID, "id");
+        env::set_var(AWS_SECRET_ACCESS_KEY, "secret");
+        env::remove_var(AWS_SESSION_TOKEN);
+        env::set_var(AWS_SECURITY_TOKEN, "legacy token");
+        let result = EnvironmentProvider.credentials().wait();
+        assert_eq!(result.unwrap().token(), &Some("legacy token".to_string()));
+        assert_eq!(
+            log_messages(AWS_SECURITY_TOKEN),
+            vec!["AWS_SECURITY_TOKEN is deprecated, use AWS_SESSION_TOKEN instead".to_owned()]
+        );
+
+        // the session token takes precedence, without a warning
+        env::set_var(AWS_SESSION_TOKEN, "token");
+        let result = EnvironmentProvider.credentials().wait();
+        env::remove_var(AWS_ACCESS_KEY_ID);
+        env::remove_var(AWS_SECRET_ACCESS_KEY);
+        env::remove_var(AWS_SESSION_TOKEN);
+        env::remove_var(AWS_SECURITY_TOKEN);
+        assert_eq!(result.unwrap().token(), &Some("token".to_string()));
+        assert_eq!(log_messages(AWS_SECURITY_TOKEN).len(), 1);
+    }
+
     #[test]
     fn get_non_temporary_credentials_from_env() {
         let _guard = lock(&ENV_MUTEX);
@@ -188,7 +224,7 @@ mod tests {
         env::remove_var(AWS_CREDENTIAL_EXPIRATION);
         assert!(result.is_err());
         assert!(match &result.err() {
-            &Some(CredentialsError{ref message}) => message.starts_with(E_INVALID_EXPIRATION),
+            &Some(CredentialsError{ref message, ..}) => message.starts_with(E_INVALID_EXPIRATION),
             _ => false,
         });
     }
@@ -196,12 +232,12 @@ mod tests {
     #[test]
     fn get_temporary_credentials_with_expiration_from_env() {
         let _guard = lock(&ENV_MUTEX);
-        let now = Utc::now();
-        let now_str = now.to_rfc3339();
+        let expiration = Utc.ymd(2018, 4, 1).and_hms_milli(12, 0, 0, 500);
+        let expiration_str = expiration.to_rfc3339();
         env::set_var(AWS_ACCESS_KEY_ID, "id");
         env::set_var(AWS_SECRET_ACCESS_KEY, "secret");
         env::set_var(AWS_SESSION_TOKEN, "token");
-        env::set_var(AWS_CREDENTIAL_EXPIRATION, now_str);
+        env::set_var(AWS_CREDENTIAL_EXPIRATION, expiration_str);
         let result = EnvironmentProvider.credentials().wait();
         env::remove_var(AWS_ACCESS_KEY_ID);
         env::remove_var(AWS_SECRET_ACCESS_KEY);
@@ -212,7 +248,7 @@ mod tests {
         assert_eq!(creds.aws_access_key_id(), "id");
         assert_eq!(creds.aws_secret_access_key(), "secret");
         assert_eq!(creds.token(), &Some("token".to_string()));
-        assert_eq!(creds.expires_at(), &Some(now));
+        assert_eq!(creds.expires_at(), &Some(expiration));
     }
 
 }
\ No newline at end of file
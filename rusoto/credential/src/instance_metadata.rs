@@ -2,7 +2,7 @@
 
 use std::time::Duration;
 
-use futures::{Future, Poll};
+use futures::{Async, Future, Poll};
 use futures::future::{FutureResult, result};
 use hyper::{Uri, Request, Method};
 use tokio_core::reactor::Handle;
@@ -13,6 +13,11 @@ use request::{HttpClient, HttpClientFuture};
 
 const AWS_CREDENTIALS_PROVIDER_IP: &str = "169.254.169.254";
 const AWS_CREDENTIALS_PROVIDER_PATH: &str = "latest/meta-data/iam/security-credentials";
+const AWS_REGION_PATH: &str = "latest/meta-data/placement/region";
+const AWS_API_TOKEN_PATH: &str = "latest/api/token";
+const AWS_API_TOKEN_TTL_HEADER: &str = "X-aws-ec2-metadata-token-ttl-seconds";
+const AWS_API_TOKEN_HEADER: &str = "X-aws-ec2-metadata-token";
+const AWS_API_TOKEN_TTL_SECONDS: &str = "21600";
 
 /// Provides AWS credentials from a resource's IAM role.
 ///
@@ -43,7 +48,8 @@ const AWS_CREDENTIALS_PROVIDER_PATH: &str = "latest/meta-data/iam/security-crede
 #[derive(Clone, Debug)]
 pub struct InstanceMetadataProvider {
     client: HttpClient,
-    timeout: Duration
+    timeout: Duration,
+    endpoint: String
 }
 
 impl InstanceMetadataProvider {
@@ -52,7 +58,8 @@ impl InstanceMetadataProvider {
         let client = HttpClient::new(handle);
         InstanceMetadataProvider {
             client: client,
-            timeout: Duration::from_secs(30)
+            timeout: Duration::from_secs(30),
+            endpoint: AWS_CREDENTIALS_PROVIDER_IP.to_owned()
         }
     }
 
@@ -60,6 +67,24 @@ impl InstanceMetadataProvider {
     pub fn set_timeout(&mut self, timeout: Duration) {
         self.timeout = timeout;
     }
+
+    /// Set the `host[:port]` of the metadata service, defaults to `169.254.169.254`.
+    pub fn set_endpoint<S>(&mut self, endpoint: S) where S: Into<String> {
+        self.endpoint = endpoint.into();
+    }
+
+    /// Query the region the instance is running in.
+    ///
+    /// An IMDSv2 session token is requested first and sent along with the region query. If
+    /// the metadata service doesn't hand out tokens, the region is queried without one.
+    pub fn region(&self) -> InstanceMetadataRegionFuture {
+        InstanceMetadataRegionFuture {
+            state: InstanceMetadataRegionFutureState::Start,
+            client: self.client.clone(),
+            timeout: self.timeout,
+            endpoint: self.endpoint.clone()
+        }
+    }
 }
 
 enum InstanceMetadataFutureState {
@@ -73,7 +98,8 @@ enum InstanceMetadataFutureState {
 pub struct InstanceMetadataProviderFuture {
     state: InstanceMetadataFutureState,
     client: HttpClient,
-    timeout: Duration
+    timeout: Duration,
+    endpoint: String
 }
 
 impl Future for InstanceMetadataProviderFuture {
@@ -83,12 +109,12 @@ impl Future for InstanceMetadataProviderFuture {
     fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
         let new_state = match self.state {
             InstanceMetadataFutureState::Start => {
-                let new_future = get_role_name(&self.client, self.timeout)?;
+                let new_future = get_role_name(&self.client, self.timeout, &self.endpoint)?;
                 InstanceMetadataFutureState::GetRoleName(new_future)
             }
             InstanceMetadataFutureState::GetRoleName(ref mut future) => {
                 let role_name = try_ready!(future.poll());
-                let new_future = get_credentials_from_role(&self.client, self.timeout, &role_name)?;
+                let new_future = get_credentials_from_role(&self.client, self.timeout, &self.endpoint, &role_name)?;
                 InstanceMetadataFutureState::GetCredentialsFromRole(new_future)
             },
             InstanceMetadataFutureState::GetCredentialsFromRole(ref mut future) => {
@@ -97,8 +123,7 @@ impl Future for InstanceMetadataProviderFuture {
                 InstanceMetadataFutureState::Done(new_future)
             },
             InstanceMetadataFutureState::Done(ref mut future) => {
-                return future.poll();
-          
+                    return future.poll();
             }
         };
         self.state = new_state;
@@ -113,16 +138,65 @@ impl ProvideAwsCredentials for InstanceMetadataProvider {
         InstanceMetadataProviderFuture {
             state: InstanceMetadataFutureState::Start,
             client: self.client.clone(),
-            timeout: self.timeout
+            timeout: self.timeout,
+            endpoint: self.endpoint.clone()
         }
     }
 }
 
+enum InstanceMetadataRegionFutureState {
+    Start,
+    GetToken(HttpClientFuture),
+    GetRegion(HttpClientFuture)
+}
+
+/// Future returned from `InstanceMetadataProvider::region`.
+pub struct InstanceMetadataRegionFuture {
+    state: InstanceMetadataRegionFutureState,
+    client: HttpClient,
+    timeout: Duration,
+    endpoint: String
+}
+
+impl Future for InstanceMetadataRegionFuture {
+    type Item = String;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let new_state = match self.state {
+            InstanceMetadataRegionFutureState::Start => {
+                let new_future = get_api_token(&self.client, self.timeout, &self.endpoint)?;
+                InstanceMetadataRegionFutureState::GetToken(new_future)
+            },
+            InstanceMetadataRegionFutureState::GetToken(ref mut future) => {
+                // fall back to IMDSv1 if no token could be obtained
+                let token = match future.poll() {
+                    Ok(Async::NotReady) => return Ok(Async::NotReady),
+                    Ok(Async::Ready(token)) => Some(token),
+                    Err(_) => None
+                };
+                let new_future = get_region(&self.client, self.timeout, &self.endpoint, token.as_ref().map(|t| t.trim()))?;
+                InstanceMetadataRegionFutureState::GetRegion(new_future)
+            },
+            InstanceMetadataRegionFutureState::GetRegion(ref mut future) => {
+                let region = try_ready!(future.poll());
+                let region = region.trim();
+                if region.is_empty() {
+                    return Err(CredentialsError::new("Instance metadata service returned an empty region"));
+                }
+                return Ok(Async::Ready(region.to_owned()));
+            }
+        };
+        self.state = new_state;
+        self.poll()
+    }
+}
+
 /// Gets the role name to get credentials for using the IAM Metadata Service (169.254.169.254).
-fn get_role_name(client: &HttpClient, timeout: Duration) -> Result<HttpClientFuture, CredentialsError> {
+fn get_role_name(client: &HttpClient, timeout: Duration, endpoint: &str) -> Result<HttpClientFuture, CredentialsError> {
     let role_name_address = format!(
         "http://{}/{}/",
-        AWS_CREDENTIALS_PROVIDER_IP,
+        endpoint,
         AWS_CREDENTIALS_PROVIDER_PATH
     );
     let uri = role_name_address.parse::<Uri>()?;
@@ -133,11 +207,12 @@ fn get_role_name(client: &HttpClient, timeout: Duration) -> Result<HttpClientFut
 fn get_credentials_from_role(
     client: &HttpClient,
     timeout: Duration,
+    endpoint: &str,
     role_name: &str
 ) -> Result<HttpClientFuture, CredentialsError> {
     let credentials_provider_url = format!(
         "http://{}/{}/{}",
-        AWS_CREDENTIALS_PROVIDER_IP,
+        endpoint,
         AWS_CREDENTIALS_PROVIDER_PATH,
         role_name
     );
@@ -145,3 +220,105 @@ fn get_credentials_from_role(
     let uri = credentials_provider_url.parse::<Uri>()?;
     Ok(client.request(Request::new(Method::Get, uri), timeout))
 }
+
+/// Requests an IMDSv2 session token from the metadata service.
+fn get_api_token(client: &HttpClient, timeout: Duration, endpoint: &str) -> Result<HttpClientFuture, CredentialsError> {
+    let token_address = format!("http://{}/{}", endpoint, AWS_API_TOKEN_PATH);
+    let uri = token_address.parse::<Uri>()?;
+    let mut request = Request::new(Method::Put, uri);
+    request.headers_mut().set_raw(AWS_API_TOKEN_TTL_HEADER, AWS_API_TOKEN_TTL_SECONDS);
+    Ok(client.request(request, timeout))
+}
+
+/// Gets the region of the instance, authenticating with `token` if there is one.
+fn get_region(
+    client: &HttpClient,
+    timeout: Duration,
+    endpoint: &str,
+    token: Option<&str>
+) -> Result<HttpClientFuture, CredentialsError> {
+    let region_address = format!("http://{}/{}", endpoint, AWS_REGION_PATH);
+    let uri = region_address.parse::<Uri>()?;
+    let mut request = Request::new(Method::Get, uri);
+    if let Some(token) = token {
+        request.headers_mut().set_raw(AWS_API_TOKEN_HEADER, token.to_owned());
+    }
+    Ok(client.request(request, timeout))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use tokio_core::reactor::Core;
+
+    use super::*;
+
+    /// Serves `responses` (status line and body per connection) and records the requests.
+    fn mock_metadata_server(responses: Vec<(&'static str, &'static str)>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = listener.local_addr().unwrap().to_string();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let read = stream.read(&mut buf).unwrap();
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..read]);
+                }
+                recorded.lock().unwrap().push(String::from_utf8(request).unwrap());
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status, body.len(), body
+                ).unwrap();
+            }
+        });
+        (endpoint, requests)
+    }
+
+    #[test]
+    fn region_uses_imdsv2_token() {
+        let (endpoint, requests) = mock_metadata_server(vec![
+            ("200 OK", "a-session-token"),
+            ("200 OK", "eu-central-1"),
+        ]);
+        let mut core = Core::new().unwrap();
+        let mut provider = InstanceMetadataProvider::new(&core.handle());
+        provider.set_endpoint(endpoint);
+
+        assert_eq!(core.run(provider.region()).unwrap(), "eu-central-1");
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("PUT /latest/api/token "));
+        assert!(requests[0].to_lowercase().contains("x-aws-ec2-metadata-token-ttl-seconds: 21600"));
+        assert!(requests[1].starts_with("GET /latest/meta-data/placement/region "));
+        assert!(requests[1].to_lowercase().contains("x-aws-ec2-metadata-token: a-session-token"));
+    }
+
+    #[test]
+    fn region_falls_back_to_imdsv1() {
+        let (endpoint, requests) = mock_metadata_server(vec![
+            ("403 Forbidden", ""),
+            ("200 OK", "us-west-2\n"),
+        ]);
+        let mut core = Core::new().unwrap();
+        let mut provider = InstanceMetadataProvider::new(&core.handle());
+        provider.set_endpoint(endpoint);
+
+        assert_eq!(core.run(provider.region()).unwrap(), "us-west-2");
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[1].starts_with("GET /latest/meta-data/placement/region "));
+        assert!(!requests[1].to_lowercase().contains("x-aws-ec2-metadata-token:"));
+    }
+}
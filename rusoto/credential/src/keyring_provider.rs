@@ -0,0 +1,207 @@
+//! The Credentials Provider to read from the system keyring.
+
+use std::fmt;
+use std::sync::Arc;
+
+use futures::{Future, Poll};
+use futures::future::{FutureResult, result};
+use keyring::{Entry, Error as KeyringError};
+
+use {AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+const AWS_ACCESS_KEY_ID: &str = "aws_access_key_id";
+const AWS_SECRET_ACCESS_KEY: &str = "aws_secret_access_key";
+const AWS_SESSION_TOKEN: &str = "aws_session_token";
+
+/// A store of secrets, identified by service and account name.
+pub trait KeyringBackend: Send + Sync {
+    /// Get the secret stored for the given service and account name, or `None` if there is none.
+    fn get_password(&self, service: &str, account: &str) -> Result<Option<String>, CredentialsError>;
+}
+
+/// The `KeyringBackend` of the operating system, e.g. the macOS Keychain or the Secret Service
+/// on Linux.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemKeyring;
+
+impl KeyringBackend for SystemKeyring {
+    fn get_password(&self, service: &str, account: &str) -> Result<Option<String>, CredentialsError> {
+        match Entry::new(service, account).and_then(|entry| entry.get_password()) {
+            Ok(password) => Ok(Some(password)),
+            Err(KeyringError::NoEntry) => Ok(None),
+            Err(e) => Err(CredentialsError::new(format!("Couldn't read from keyring: {}", e))),
+        }
+    }
+}
+
+/// Provides AWS credentials from the system keyring.
+///
+/// The credentials are stored as separate entries of the service, with the account names
+/// `ACCOUNT:aws_access_key_id`, `ACCOUNT:aws_secret_access_key` and, optionally,
+/// `ACCOUNT:aws_session_token`.
+#[derive(Clone)]
+pub struct KeyringProvider {
+    service: String,
+    account: String,
+    backend: Arc<KeyringBackend>,
+}
+
+impl KeyringProvider {
+    /// Create a new `KeyringProvider` reading the credentials of the given account from
+    /// the entries of the given service in the system keyring.
+    pub fn new<S, A>(service: S, account: A) -> KeyringProvider
+    where
+        S: Into<String>,
+        A: Into<String>,
+    {
+        KeyringProvider::with_backend(service, account, Arc::new(SystemKeyring))
+    }
+
+    /// Create a new `KeyringProvider` reading from the given backend instead of the system
+    /// keyring.
+    pub fn with_backend<S, A>(service: S, account: A, backend: Arc<KeyringBackend>) -> KeyringProvider
+    where
+        S: Into<String>,
+        A: Into<String>,
+    {
+        KeyringProvider {
+            service: service.into(),
+            account: account.into(),
+            backend: backend,
+        }
+    }
+
+    /// Get a reference to the service name.
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// Get a reference to the account name.
+    pub fn account(&self) -> &str {
+        &self.account
+    }
+
+    fn entry(&self, key: &str) -> Result<Option<String>, CredentialsError> {
+        self.backend.get_password(&self.service, &format!("{}:{}", self.account, key))
+    }
+
+    fn required_entry(&self, key: &str) -> Result<String, CredentialsError> {
+        self.entry(key)?.ok_or_else(|| {
+            CredentialsError::new(format!(
+                "No {} for account {:?} of service {:?} in keyring",
+                key, self.account, self.service
+            ))
+        })
+    }
+
+    fn credentials_from_keyring(&self) -> Result<AwsCredentials, CredentialsError> {
+        let key = self.required_entry(AWS_ACCESS_KEY_ID)?;
+        let secret = self.required_entry(AWS_SECRET_ACCESS_KEY)?;
+        let token = self.entry(AWS_SESSION_TOKEN)?;
+        Ok(AwsCredentials::new(key, secret, token, None))
+    }
+}
+
+impl fmt::Debug for KeyringProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("KeyringProvider")
+            .field("service", &self.service)
+            .field("account", &self.account)
+            .finish()
+    }
+}
+
+pub struct KeyringProviderFuture {
+    inner: FutureResult<AwsCredentials, CredentialsError>
+}
+
+impl Future for KeyringProviderFuture {
+    type Item = AwsCredentials;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+impl ProvideAwsCredentials for KeyringProvider {
+    type Future = KeyringProviderFuture;
+
+    fn credentials(&self) -> Self::Future {
+        KeyringProviderFuture {
+            inner: result(self.credentials_from_keyring())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use futures::Future;
+
+    use {CredentialsError, ProvideAwsCredentials};
+    use super::*;
+
+    /// Keeps the entries of the "aws" service in memory, keyed by account name.
+    struct MockKeyring(HashMap<String, String>);
+
+    impl KeyringBackend for MockKeyring {
+        fn get_password(&self, service: &str, account: &str) -> Result<Option<String>, CredentialsError> {
+            assert_eq!(service, "aws");
+            Ok(self.0.get(account).cloned())
+        }
+    }
+
+    fn provider(entries: &[(&str, &str)]) -> KeyringProvider {
+        let entries = entries
+            .iter()
+            .map(|&(account, password)| (account.to_owned(), password.to_owned()))
+            .collect();
+        KeyringProvider::with_backend("aws", "dev", Arc::new(MockKeyring(entries)))
+    }
+
+    #[test]
+    fn keyring_provider_happy_path() {
+        let provider = provider(&[
+            ("dev:aws_access_key_id", "foo"),
+            ("dev:aws_secret_access_key", "bar"),
+            ("dev:aws_session_token", "baz"),
+            ("other:aws_access_key_id", "qux"),
+        ]);
+        let creds = provider.credentials().wait().unwrap();
+        assert_eq!(creds.aws_access_key_id(), "foo");
+        assert_eq!(creds.aws_secret_access_key(), "bar");
+        assert_eq!(creds.token(), &Some("baz".to_owned()));
+    }
+
+    #[test]
+    fn keyring_provider_without_token() {
+        let provider = provider(&[
+            ("dev:aws_access_key_id", "foo"),
+            ("dev:aws_secret_access_key", "bar"),
+        ]);
+        let creds = provider.credentials().wait().unwrap();
+        assert_eq!(creds.token(), &None);
+    }
+
+    #[test]
+    fn keyring_provider_missing_entries() {
+        let without_secret = provider(&[("dev:aws_access_key_id", "foo")]);
+        assert_eq!(
+            without_secret.credentials().wait().err(),
+            Some(CredentialsError::new(
+                "No aws_secret_access_key for account \"dev\" of service \"aws\" in keyring"
+            ))
+        );
+
+        let empty = provider(&[]);
+        assert_eq!(
+            empty.credentials().wait().err(),
+            Some(CredentialsError::new(
+                "No aws_access_key_id for account \"dev\" of service \"aws\" in keyring"
+            ))
+        );
+    }
+}
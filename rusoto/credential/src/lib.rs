@@ -11,22 +11,51 @@ extern crate chrono;
 extern crate futures;
 extern crate hyper;
 extern crate hyper_tls;
+#[cfg(feature = "keyring")]
+extern crate keyring;
+#[macro_use]
+extern crate lazy_static;
+#[macro_use]
+extern crate log;
 extern crate regex;
 extern crate serde_json;
 extern crate tokio_core;
 
+pub use cli_cache::CliCacheProvider;
+pub use clock::{Clock, SystemClock};
 pub use environment::EnvironmentProvider;
 pub use container::ContainerProvider;
-pub use static_provider::StaticProvider;
-pub use instance_metadata::InstanceMetadataProvider;
-pub use profile::ProfileProvider;
+pub use static_provider::{AnonymousProvider, StaticProvider};
+pub use stdin_provider::StdinProvider;
+pub use http_provider::HttpProvider;
+pub use instance_metadata::{InstanceMetadataProvider, InstanceMetadataRegionFuture};
+pub use json_file::JsonFileProvider;
+pub use logging_provider::LoggingProvider;
+#[cfg(feature = "keyring")]
+pub use keyring_provider::{KeyringBackend, KeyringProvider, SystemKeyring};
+pub use profile::{CredentialsDiff, MergeMode, ProfileDiff, ProfileProvider, ProfileWarning,
+                  ResolutionEvent, ResolutionSource, RoleSource, diff_credentials};
+pub use retrying_provider::RetryingProvider;
+pub use sso_cache::{cached_sso_token, cached_sso_token_with_clock};
+pub use vault_response::VaultResponseProvider;
 
 mod request;
+mod cli_cache;
+mod clock;
 mod container;
 mod environment;
 mod static_provider;
+mod stdin_provider;
+mod http_provider;
 mod instance_metadata;
+mod json_file;
+mod logging_provider;
+#[cfg(feature = "keyring")]
+mod keyring_provider;
 mod profile;
+mod retrying_provider;
+mod sso_cache;
+mod vault_response;
 pub(crate) mod test_utils;
 pub mod claims;
 
@@ -35,14 +64,14 @@ use std::fmt;
 use std::error::Error;
 use std::io::Error as IoError;
 use std::ops::Deref;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 
 use chrono::{Duration as ChronoDuration, Utc, DateTime, ParseError};
 use futures::{Async, Future, Poll};
-use futures::future::{Either, Shared, SharedItem, err};
+use futures::future::{Either, FutureResult, Shared, SharedItem, err, ok};
 use hyper::{Error as HyperError};
 use hyper::error::UriError;
 use serde_json::{from_str as json_from_str, Value};
@@ -57,6 +86,7 @@ pub struct AwsCredentials {
     token: Option<String>,
     expires_at: Option<DateTime<Utc>>,
     claims: BTreeMap<String, String>,
+    anonymous: bool,
 }
 
 impl AwsCredentials {
@@ -78,9 +108,25 @@ impl AwsCredentials {
             token: token,
             expires_at: expires_at,
             claims: BTreeMap::new(),
+            anonymous: false,
+        }
+    }
+
+    /// Create the sentinel credentials for anonymous, unsigned requests, e.g. to public S3
+    /// buckets. Request signers skip signing when given these credentials.
+    pub fn anonymous() -> AwsCredentials {
+        AwsCredentials {
+            anonymous: true,
+            ..AwsCredentials::new("", "", None, None)
         }
     }
 
+    /// Whether these are the sentinel credentials created by `anonymous`, credentials that
+    /// merely happen to be empty are not.
+    pub fn is_anonymous(&self) -> bool {
+        self.anonymous
+    }
+
     /// Get a reference to the access key ID.
     pub fn aws_access_key_id(&self) -> &str {
         &self.key
@@ -101,13 +147,19 @@ impl AwsCredentials {
         &self.token
     }
 
-    /// Determine whether or not the credentials are expired.
-    fn credentials_are_expired(&self) -> bool {
+    /// Get the access key ID, secret access key and access token at once, e.g. for
+    /// passing them to a request signer.
+    pub fn as_parts(&self) -> (&str, &str, Option<&str>) {
+        (&self.key, &self.secret, self.token.as_ref().map(|token| &token[..]))
+    }
+
+    /// Determine whether or not the credentials are expired at the given time.
+    fn credentials_are_expired(&self, now: DateTime<Utc>) -> bool {
         match self.expires_at {
             Some(ref e) =>
                 // This is a rough hack to hopefully avoid someone requesting creds then sitting on them
                 // before issuing the request:
-               *e < Utc::now() + ChronoDuration::seconds(20),
+               *e < now + ChronoDuration::seconds(20),
             None => false,
         }
     }
@@ -139,10 +191,15 @@ impl fmt::Debug for AwsCredentials {
 ///
 /// This generally is an error message from one of our underlying libraries, however
 /// we wrap it up with this type so we can export one single error type.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CredentialsError {
     /// The underlying error message for the credentials error.
     pub message: String,
+    /// Whether the error is transient, e.g. a failed connection, and fetching the
+    /// credentials again may succeed.
+    retryable: bool,
+    /// The start URL of the SSO session if the cached SSO token has expired.
+    sso_start_url: Option<String>,
 }
 
 impl CredentialsError {
@@ -153,7 +210,43 @@ impl CredentialsError {
     where
         S: Into<String>,
     {
-        CredentialsError { message: message.into() }
+        CredentialsError { message: message.into(), retryable: false, sso_start_url: None }
+    }
+
+    /// Creates a new Credentials Error for a transient failure, for which fetching
+    /// the credentials again may succeed.
+    ///
+    /// * `message` - The Error message for this CredentialsError.
+    pub fn retryable<S>(message: S) -> CredentialsError
+    where
+        S: Into<String>,
+    {
+        CredentialsError { message: message.into(), retryable: true, sso_start_url: None }
+    }
+
+    /// Creates a new Credentials Error for an expired SSO token of the session with the
+    /// given start URL, which can be renewed by logging in again, e.g. using `aws sso login`.
+    pub fn sso_token_expired<S>(start_url: S) -> CredentialsError
+    where
+        S: Into<String>,
+    {
+        let start_url = start_url.into();
+        CredentialsError {
+            message: format!("The SSO token for {} has expired, please log in again", start_url),
+            retryable: false,
+            sso_start_url: Some(start_url),
+        }
+    }
+
+    /// Whether the error is transient and fetching the credentials again may succeed.
+    pub fn is_retryable(&self) -> bool {
+        self.retryable
+    }
+
+    /// The start URL of the SSO session to log in to again if the error was caused by an
+    /// expired SSO token.
+    pub fn expired_sso_start_url(&self) -> Option<&str> {
+        self.sso_start_url.as_ref().map(|url| &url[..])
     }
 }
 
@@ -189,7 +282,7 @@ impl From<UriError> for CredentialsError {
 
 impl From<HyperError> for CredentialsError {
     fn from(err: HyperError) -> CredentialsError {
-        CredentialsError::new(
+        CredentialsError::retryable(
             format!("Couldn't connect to credentials provider: {}", err),
         )
     }
@@ -202,6 +295,58 @@ pub trait ProvideAwsCredentials {
 
     /// Produce a new `AwsCredentials` future.
     fn credentials(&self) -> Self::Future;
+
+    /// Check that credentials can be resolved, without making a request using them, e.g. to
+    /// fail fast at startup with the reason the credentials are unavailable.
+    ///
+    /// The default implementation resolves the credentials by waiting on `credentials` and
+    /// discards them. As it blocks the current thread, it mustn't be called on the thread
+    /// running the event loop providers like `InstanceMetadataProvider` are created with.
+    fn health_check(&self) -> Result<(), CredentialsError> {
+        self.credentials().wait().map(|_| ())
+    }
+}
+
+/// Allows sharing a single provider, and whatever it caches, between multiple clients.
+impl<P: ProvideAwsCredentials> ProvideAwsCredentials for Arc<P> {
+    type Future = P::Future;
+
+    fn credentials(&self) -> Self::Future {
+        P::credentials(self)
+    }
+
+    fn health_check(&self) -> Result<(), CredentialsError> {
+        P::health_check(self)
+    }
+}
+
+/// Hands out clones of fixed credentials, without any provider logic. Mostly useful for
+/// passing credentials to code expecting a provider in tests.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate futures;
+/// extern crate rusoto_credential;
+///
+/// use futures::Future;
+/// use rusoto_credential::{AwsCredentials, ProvideAwsCredentials};
+///
+/// fn access_key_id<P: ProvideAwsCredentials>(provider: &P) -> String {
+///     provider.credentials().wait().unwrap().aws_access_key_id().to_owned()
+/// }
+///
+/// fn main() {
+///     let credentials = AwsCredentials::new("AKIDEXAMPLE", "secret", None, None);
+///     assert_eq!(access_key_id(&credentials), "AKIDEXAMPLE");
+/// }
+/// ```
+impl ProvideAwsCredentials for AwsCredentials {
+    type Future = FutureResult<AwsCredentials, CredentialsError>;
+
+    fn credentials(&self) -> Self::Future {
+        ok(self.clone())
+    }
 }
 
 /// Wrapper for `ProvideAwsCredentials` that caches the credentials returned by the
@@ -214,6 +359,7 @@ pub trait ProvideAwsCredentials {
 pub struct BaseAutoRefreshingProvider<P: ProvideAwsCredentials + 'static, T> {
     credentials_provider: P,
     shared_future: T,
+    clock: Arc<Clock>,
 }
 
 impl<P: ProvideAwsCredentials + 'static, T> BaseAutoRefreshingProvider<P, T> {
@@ -229,6 +375,12 @@ impl<P: ProvideAwsCredentials + 'static, T> BaseAutoRefreshingProvider<P, T> {
     pub fn get_mut(&mut self) -> &mut P {
         &mut self.credentials_provider
     }
+
+    /// Set the clock used to decide whether the cached credentials are expired, e.g. a fake
+    /// clock in tests. Defaults to `SystemClock`.
+    pub fn set_clock(&mut self, clock: Arc<Clock>) {
+        self.clock = clock;
+    }
 }
 
 enum AutoRefreshingFutureInner<P: ProvideAwsCredentials + 'static> {
@@ -237,12 +389,12 @@ enum AutoRefreshingFutureInner<P: ProvideAwsCredentials + 'static> {
 }
 
 impl<P: ProvideAwsCredentials + 'static> AutoRefreshingFutureInner<P> {
-    fn from_shared_future(future: &mut Shared<P::Future>, provider: &P) -> Self {
+    fn from_shared_future(future: &mut Shared<P::Future>, provider: &P, clock: &Clock) -> Self {
         match future.peek() {
             // no result from the future yet, let's keep using it
             None => AutoRefreshingFutureInner::NotCached(future.clone()),
             // successful result from the future, use it if not expired
-            Some(Ok(ref creds)) if !creds.credentials_are_expired() =>
+            Some(Ok(ref creds)) if !creds.credentials_are_expired(clock.now()) =>
                 AutoRefreshingFutureInner::Cached(creds.clone()),
             Some(_) => {
                 // else launch a new future
@@ -278,7 +430,11 @@ impl<P: ProvideAwsCredentials + 'static> Future for AutoRefreshingProviderFuture
             AutoRefreshingFutureInner::Cached(ref creds) => Ok(Async::Ready(creds.deref().clone())),
             AutoRefreshingFutureInner::NotCached(ref mut future) => {
                 match future.poll() {
-                    Err(err) => Err(CredentialsError { message: err.message.to_owned() }),
+                    Err(err) => Err(CredentialsError {
+                        message: err.message.to_owned(),
+                        retryable: err.retryable,
+                        sso_start_url: err.sso_start_url.clone(),
+                    }),
                     Ok(Async::NotReady) => Ok(Async::NotReady),
                     Ok(Async::Ready(item)) => Ok(Async::Ready(item.deref().clone()))
                 }
@@ -298,6 +454,7 @@ impl<P: ProvideAwsCredentials + 'static> AutoRefreshingProviderSync<P> {
         Ok(BaseAutoRefreshingProvider {
             credentials_provider: provider,
             shared_future: Mutex::new(future.shared()),
+            clock: Arc::new(SystemClock),
         })
     }
 }
@@ -310,7 +467,11 @@ impl<P: ProvideAwsCredentials + 'static> ProvideAwsCredentials for AutoRefreshin
             "Failed to lock the cached credentials Mutex",
         );
         AutoRefreshingProviderFuture {
-            inner: AutoRefreshingFutureInner::from_shared_future(&mut shared_future, &self.credentials_provider)
+            inner: AutoRefreshingFutureInner::from_shared_future(
+                &mut shared_future,
+                &self.credentials_provider,
+                &*self.clock,
+            )
         }
     }
 }
@@ -327,6 +488,7 @@ impl<P: ProvideAwsCredentials + 'static> AutoRefreshingProvider<P> {
         Ok(BaseAutoRefreshingProvider {
             credentials_provider: provider,
             shared_future: RefCell::new(future.shared()),
+            clock: Arc::new(SystemClock),
         })
     }
 }
@@ -337,7 +499,11 @@ impl<P: ProvideAwsCredentials + 'static> ProvideAwsCredentials for AutoRefreshin
     fn credentials(&self) -> Self::Future {
         let mut shared_future = self.shared_future.borrow_mut();
         AutoRefreshingProviderFuture {
-            inner: AutoRefreshingFutureInner::from_shared_future(&mut shared_future, &self.credentials_provider)
+            inner: AutoRefreshingFutureInner::from_shared_future(
+                &mut shared_future,
+                &self.credentials_provider,
+                &*self.clock,
+            )
         }
     }
 }
@@ -541,9 +707,6 @@ fn parse_credentials_from_aws_service(response: &str) -> Result<AwsCredentials,
     ))
 }
 
-#[cfg(test)]
-#[macro_use] extern crate lazy_static;
-
 #[cfg(test)]
 #[macro_use] extern crate quickcheck;
 
@@ -554,7 +717,8 @@ mod tests {
     use std::path::Path;
 
     use futures::Future;
-    use test_utils::{is_secret_hidden_behind_asterisks, SECRET};
+    use futures::future::{FutureResult, ok};
+    use test_utils::{is_secret_hidden_behind_asterisks, FixedClock, SECRET};
 
     use super::*;
 
@@ -608,6 +772,72 @@ mod tests {
         );
     }
 
+    /// Counts how often credentials are requested, which expire at `expires_at`.
+    struct CountingProvider {
+        calls: Arc<Mutex<u32>>,
+        expires_at: DateTime<Utc>,
+    }
+
+    impl ProvideAwsCredentials for CountingProvider {
+        type Future = FutureResult<AwsCredentials, CredentialsError>;
+
+        fn credentials(&self) -> Self::Future {
+            *self.calls.lock().unwrap() += 1;
+            ok(AwsCredentials::new("key", "secret", None, Some(self.expires_at)))
+        }
+    }
+
+    #[test]
+    fn auto_refreshing_provider_refreshes_at_expiry_of_fake_clock() {
+        let now = Utc::now();
+        let clock = Arc::new(FixedClock::new(now));
+        let calls = Arc::new(Mutex::new(0));
+        let mut provider = AutoRefreshingProviderSync::with_mutex(CountingProvider {
+            calls: calls.clone(),
+            expires_at: now + ChronoDuration::minutes(10),
+        }).unwrap();
+        provider.set_clock(clock.clone());
+
+        provider.credentials().wait().unwrap();
+        provider.credentials().wait().unwrap();
+        assert_eq!(*calls.lock().unwrap(), 1);
+
+        // within the expiry buffer
+        clock.set(now + ChronoDuration::minutes(10) - ChronoDuration::seconds(10));
+        provider.credentials().wait().unwrap();
+        assert_eq!(*calls.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn aws_credentials_as_parts() {
+        let credentials = AwsCredentials::new("key", "secret", Some("token".to_owned()), None);
+        assert_eq!(credentials.as_parts(), ("key", "secret", Some("token")));
+        assert_eq!(
+            credentials.as_parts(),
+            (
+                credentials.aws_access_key_id(),
+                credentials.aws_secret_access_key(),
+                credentials.token().as_ref().map(|token| &token[..]),
+            )
+        );
+
+        let credentials = AwsCredentials::new("key", "secret", None, None);
+        assert_eq!(credentials.as_parts(), ("key", "secret", None));
+    }
+
+    #[test]
+    fn credentials_provide_themselves() {
+        fn resolve<P: ProvideAwsCredentials>(provider: P) -> AwsCredentials {
+            provider.credentials().wait().unwrap()
+        }
+
+        let credentials = AwsCredentials::new("key", "secret", Some("token".to_owned()), None);
+        assert_eq!(resolve(credentials.clone()).as_parts(), ("key", "secret", Some("token")));
+        // can be wrapped like any other provider
+        let shared = Arc::new(credentials);
+        assert_eq!(resolve(shared).as_parts(), ("key", "secret", Some("token")));
+    }
+
     #[test]
     quickcheck! {
         fn test_aws_credentials_secrets_not_in_debug(
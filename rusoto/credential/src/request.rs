@@ -34,10 +34,14 @@ impl Future for RequestFuture {
                         Ok(Async::NotReady)
                     },
                     Async::Ready(res) => {
-                        if !res.status().is_success() {
-                            Err(CredentialsError {
-                                message: format!("Invalid Response Code: {}", res.status())
-                            })
+                        if res.status().is_server_error() {
+                            Err(CredentialsError::retryable(
+                                format!("Invalid Response Code: {}", res.status())
+                            ))
+                        } else if !res.status().is_success() {
+                            Err(CredentialsError::new(
+                                format!("Invalid Response Code: {}", res.status())
+                            ))
                         } else {
                             *self = RequestFuture::Buffering(res.body().concat2());
                             self.poll()
@@ -75,7 +79,7 @@ impl Future for HttpClientFuture {
     fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
         match self.0 {
             ClientFutureInner::Error(ref message) =>
-                Err(CredentialsError { message: message.clone() }),
+                Err(CredentialsError::new(message.clone())),
             ClientFutureInner::Request(ref mut select_future) => {
                 match select_future.poll() {
                     Err(Either::A((err, _))) =>
@@ -87,7 +91,7 @@ impl Future for HttpClientFuture {
                     Ok(Async::Ready(Either::A((body, _)))) =>
                         Ok(Async::Ready(body)),
                     Ok(Async::Ready(Either::B(((), _)))) =>
-                        Err(CredentialsError { message: "Request timed out".into() })
+                        Err(CredentialsError::retryable("Request timed out"))
                 }
             }
         }
@@ -0,0 +1,215 @@
+//! Wraps a Credentials Provider and retries transient failures with exponential backoff.
+
+use std::time::Duration;
+
+use futures::{Async, Future, Poll};
+use tokio_core::reactor::{Handle, Timeout};
+
+use {AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+/// Wraps a `ProvideAwsCredentials` and retries fetching credentials from it when
+/// it fails with a retryable `CredentialsError`, e.g. because the instance metadata
+/// service couldn't be reached.
+///
+/// The delay between attempts starts at 100 milliseconds and doubles after each
+/// attempt. By default, up to 3 retries are made. Errors which aren't retryable,
+/// like a missing profile, are returned immediately.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate rusoto_credential;
+/// extern crate tokio_core;
+///
+/// use rusoto_credential::{InstanceMetadataProvider, RetryingProvider};
+/// use tokio_core::reactor::Core;
+///
+/// fn main() {
+///   let core = Core::new().unwrap();
+///
+///   let provider = InstanceMetadataProvider::new(&core.handle());
+///   let mut provider = RetryingProvider::new(provider, &core.handle());
+///   provider.set_max_retries(5);
+///
+///   // ...
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryingProvider<P> {
+    provider: P,
+    handle: Handle,
+    max_retries: u32,
+    base_delay: Duration,
+}
+
+impl<P> RetryingProvider<P> {
+    /// Wrap the given provider.
+    pub fn new(provider: P, handle: &Handle) -> RetryingProvider<P> {
+        RetryingProvider {
+            provider: provider,
+            handle: handle.clone(),
+            max_retries: 3,
+            base_delay: Duration::from_millis(100),
+        }
+    }
+
+    /// Get a shared reference to the wrapped provider.
+    pub fn get_ref(&self) -> &P {
+        &self.provider
+    }
+
+    /// Get a mutable reference to the wrapped provider.
+    pub fn get_mut(&mut self) -> &mut P {
+        &mut self.provider
+    }
+
+    /// Set the maximum number of retries after the initial attempt.
+    pub fn set_max_retries(&mut self, max_retries: u32) {
+        self.max_retries = max_retries;
+    }
+
+    /// Set the delay before the first retry. It is doubled for every subsequent retry.
+    pub fn set_base_delay(&mut self, base_delay: Duration) {
+        self.base_delay = base_delay;
+    }
+}
+
+enum RetryingFutureState<F> {
+    Fetching(F),
+    Waiting(Timeout),
+}
+
+/// Future returned from `RetryingProvider`.
+pub struct RetryingProviderFuture<P: ProvideAwsCredentials> {
+    provider: P,
+    handle: Handle,
+    state: RetryingFutureState<P::Future>,
+    retries: u32,
+    max_retries: u32,
+    base_delay: Duration,
+}
+
+impl<P: ProvideAwsCredentials> Future for RetryingProviderFuture<P> {
+    type Item = AwsCredentials;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let new_state = match self.state {
+            RetryingFutureState::Fetching(ref mut future) => match future.poll() {
+                Ok(result) => return Ok(result),
+                Err(ref err) if err.is_retryable() && self.retries < self.max_retries => {
+                    // cap the exponent, the delay is long enough by then anyway
+                    let delay = self.base_delay * 2u32.pow(self.retries.min(16));
+                    self.retries += 1;
+                    RetryingFutureState::Waiting(Timeout::new(delay, &self.handle)?)
+                }
+                Err(err) => return Err(err),
+            },
+            RetryingFutureState::Waiting(ref mut timeout) => match timeout.poll()? {
+                Async::NotReady => return Ok(Async::NotReady),
+                Async::Ready(()) => RetryingFutureState::Fetching(self.provider.credentials()),
+            },
+        };
+        self.state = new_state;
+        self.poll()
+    }
+}
+
+impl<P: ProvideAwsCredentials + Clone + 'static> ProvideAwsCredentials for RetryingProvider<P> {
+    type Future = RetryingProviderFuture<P>;
+
+    fn credentials(&self) -> Self::Future {
+        RetryingProviderFuture {
+            provider: self.provider.clone(),
+            handle: self.handle.clone(),
+            state: RetryingFutureState::Fetching(self.provider.credentials()),
+            retries: 0,
+            max_retries: self.max_retries,
+            base_delay: self.base_delay,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    use futures::future::{FutureResult, err, ok};
+    use tokio_core::reactor::Core;
+
+    use {AwsCredentials, CredentialsError, ProvideAwsCredentials};
+    use super::*;
+
+    /// Fails with the given error until `failures` attempts have been made.
+    #[derive(Clone)]
+    struct FlakyProvider {
+        attempts: Rc<Cell<u32>>,
+        failures: u32,
+        retryable: bool,
+    }
+
+    impl FlakyProvider {
+        fn new(failures: u32, retryable: bool) -> FlakyProvider {
+            FlakyProvider { attempts: Rc::new(Cell::new(0)), failures: failures, retryable: retryable }
+        }
+    }
+
+    impl ProvideAwsCredentials for FlakyProvider {
+        type Future = FutureResult<AwsCredentials, CredentialsError>;
+
+        fn credentials(&self) -> Self::Future {
+            self.attempts.set(self.attempts.get() + 1);
+            if self.attempts.get() <= self.failures {
+                if self.retryable {
+                    err(CredentialsError::retryable("connection refused"))
+                } else {
+                    err(CredentialsError::new("profile not found"))
+                }
+            } else {
+                ok(AwsCredentials::new("key", "secret", None, None))
+            }
+        }
+    }
+
+    fn retrying(core: &Core, provider: &FlakyProvider) -> RetryingProvider<FlakyProvider> {
+        let mut provider = RetryingProvider::new(provider.clone(), &core.handle());
+        provider.set_base_delay(Duration::from_millis(1));
+        provider
+    }
+
+    #[test]
+    fn retries_until_success() {
+        let mut core = Core::new().unwrap();
+        let flaky = FlakyProvider::new(2, true);
+        let provider = retrying(&core, &flaky);
+
+        let creds = core.run(provider.credentials()).unwrap();
+        assert_eq!(creds.aws_access_key_id(), "key");
+        assert_eq!(flaky.attempts.get(), 3);
+    }
+
+    #[test]
+    fn gives_up_after_max_retries() {
+        let mut core = Core::new().unwrap();
+        let flaky = FlakyProvider::new(10, true);
+        let mut provider = retrying(&core, &flaky);
+        provider.set_max_retries(2);
+
+        let result = core.run(provider.credentials());
+        assert_eq!(result.err(), Some(CredentialsError::retryable("connection refused")));
+        assert_eq!(flaky.attempts.get(), 3);
+    }
+
+    #[test]
+    fn does_not_retry_permanent_errors() {
+        let mut core = Core::new().unwrap();
+        let flaky = FlakyProvider::new(2, false);
+        let provider = retrying(&core, &flaky);
+
+        let result = core.run(provider.credentials());
+        assert_eq!(result.err(), Some(CredentialsError::new("profile not found")));
+        assert_eq!(flaky.attempts.get(), 1);
+    }
+}
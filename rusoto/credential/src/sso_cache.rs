@@ -0,0 +1,102 @@
+//! Reads SSO tokens cached by `aws sso login`.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use {Clock, CredentialsError, SystemClock};
+use json_file::read_json_file;
+
+/// Read the access token from an SSO token cache file, as written by `aws sso login` to
+/// `~/.aws/sso/cache/`.
+///
+/// The `expiresAt` field of the cache file is checked, so an expired token results in a
+/// `CredentialsError` whose `expired_sso_start_url` is set, before any request using the
+/// token is made.
+pub fn cached_sso_token<P>(cache_file: P) -> Result<String, CredentialsError>
+where
+    P: AsRef<Path>,
+{
+    cached_sso_token_with_clock(cache_file, &SystemClock)
+}
+
+/// Read the access token from an SSO token cache file like `cached_sso_token`, deciding
+/// whether it is expired using the given clock.
+pub fn cached_sso_token_with_clock<P>(cache_file: P, clock: &Clock) -> Result<String, CredentialsError>
+where
+    P: AsRef<Path>,
+{
+    let source = format!("SSO cache file {}", cache_file.as_ref().display());
+    let json_object = read_json_file(cache_file.as_ref(), &source)?;
+    let field = |key: &str| {
+        json_object.get(key).and_then(Value::as_str).ok_or_else(|| {
+            CredentialsError::new(format!("Couldn't find {} in {}", key, source))
+        })
+    };
+
+    let start_url = field("startUrl")?;
+    let expires_at = parse_expires_at(field("expiresAt")?)?;
+    if expires_at <= clock.now() {
+        return Err(CredentialsError::sso_token_expired(start_url));
+    }
+    field("accessToken").map(str::to_owned)
+}
+
+/// Parses the `expiresAt` field, which older versions of the AWS CLI write with a
+/// `UTC` suffix instead of `Z`, e.g. `2018-04-01T12:00:00UTC`.
+fn parse_expires_at(expires_at: &str) -> Result<DateTime<Utc>, CredentialsError> {
+    let expires_at = if expires_at.ends_with("UTC") {
+        format!("{}Z", &expires_at[..expires_at.len() - 3])
+    } else {
+        expires_at.to_owned()
+    };
+    Ok(expires_at.parse::<DateTime<Utc>>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use CredentialsError;
+    use test_utils::FixedClock;
+    use super::*;
+
+    #[test]
+    fn cached_sso_token_valid() {
+        let clock = FixedClock::new(Utc.ymd(2018, 4, 1).and_hms(11, 0, 0));
+        let token = cached_sso_token_with_clock("tests/sample-data/sso_cache_token.json", &clock);
+        assert_eq!(token, Ok("cached_access_token".to_owned()));
+    }
+
+    #[test]
+    fn cached_sso_token_expired() {
+        let clock = FixedClock::new(Utc.ymd(2018, 4, 1).and_hms(12, 0, 0));
+        let error = cached_sso_token_with_clock("tests/sample-data/sso_cache_token.json", &clock)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            CredentialsError::sso_token_expired("https://my-sso-portal.awsapps.com/start")
+        );
+        assert_eq!(
+            error.expired_sso_start_url(),
+            Some("https://my-sso-portal.awsapps.com/start")
+        );
+
+        let error = cached_sso_token("tests/sample-data/sso_cache_expired_token.json").unwrap_err();
+        assert_eq!(
+            error.expired_sso_start_url(),
+            Some("https://my-sso-portal.awsapps.com/start")
+        );
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn cached_sso_token_other_errors() {
+        let error = cached_sso_token("tests/sample-data/default_profile_credentials").unwrap_err();
+        assert_eq!(error.expired_sso_start_url(), None);
+
+        let error = cached_sso_token("tests/sample-data/no_such_file.json").unwrap_err();
+        assert_eq!(error.expired_sso_start_url(), None);
+    }
+}
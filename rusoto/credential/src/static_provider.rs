@@ -87,6 +87,19 @@ impl ProvideAwsCredentials for StaticProvider {
     }
 }
 
+/// Provides the anonymous credentials of `AwsCredentials::anonymous`, for clients making
+/// unsigned requests to endpoints accepting them, like public S3 buckets.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AnonymousProvider;
+
+impl ProvideAwsCredentials for AnonymousProvider {
+    type Future = FutureResult<AwsCredentials, CredentialsError>;
+
+    fn credentials(&self) -> Self::Future {
+        ok(AwsCredentials::anonymous())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use futures::Future;
@@ -116,6 +129,18 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_anonymous_provider() {
+        let credentials = AnonymousProvider.credentials().wait().unwrap();
+        assert!(credentials.is_anonymous());
+        assert!(AwsCredentials::anonymous().is_anonymous());
+
+        let provider = StaticProvider::new_minimal("fake-key".to_owned(), "fake-secret".to_owned());
+        let credentials = provider.credentials().wait().unwrap();
+        assert!(!credentials.is_anonymous());
+        assert!(!AwsCredentials::new("", "", None, None).is_anonymous());
+    }
+
     #[test]
     fn test_static_provider_custom_time_expiration() {
         let start_time = Utc::now();
@@ -1,6 +1,12 @@
 #![cfg(test)]
 
 use std::fmt::Debug;
+use std::sync::{Mutex, Once, ONCE_INIT};
+
+use chrono::{DateTime, Utc};
+use log::{self, Level, Log, Metadata, Record};
+
+use Clock;
 
 pub const SECRET: &str = &"TtnuieannGt2rGuie2t8Tt7urarg5nauedRndrur";
 
@@ -8,3 +14,64 @@ pub fn is_secret_hidden_behind_asterisks<D>(obj: &D) -> bool where D: Debug {
     let debug = format!("{:?}", obj);
     !debug.contains(SECRET) && debug.contains("**********")
 }
+
+/// A `Clock` standing still at the given time, until it's moved using `set`.
+#[derive(Debug)]
+pub struct FixedClock(Mutex<DateTime<Utc>>);
+
+impl FixedClock {
+    pub fn new(now: DateTime<Utc>) -> FixedClock {
+        FixedClock(Mutex::new(now))
+    }
+
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.0.lock().unwrap() = now;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap()
+    }
+}
+
+lazy_static! {
+    static ref MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+}
+
+/// Keeps all log messages in `MESSAGES`.
+struct CaptureLogger;
+
+impl Log for CaptureLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Debug
+    }
+
+    fn log(&self, record: &Record) {
+        MESSAGES.lock().unwrap().push(format!("{}", record.args()));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CaptureLogger = CaptureLogger;
+static INIT: Once = ONCE_INIT;
+
+/// Install the logger capturing all log messages, once for all tests.
+pub fn init_logger() {
+    INIT.call_once(|| {
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(Level::Debug.to_level_filter());
+    });
+}
+
+/// The log messages mentioning `label`, which has to be unique per test.
+pub fn log_messages(label: &str) -> Vec<String> {
+    MESSAGES
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|message| message.contains(label))
+        .cloned()
+        .collect()
+}
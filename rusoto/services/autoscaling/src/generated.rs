@@ -8581,7 +8581,7 @@ pub enum AttachInstancesError {
 impl AttachInstancesError {
     pub fn from_body(body: &str) -> AttachInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -8658,7 +8658,7 @@ pub enum AttachLoadBalancerTargetGroupsError {
 impl AttachLoadBalancerTargetGroupsError {
     pub fn from_body(body: &str) -> AttachLoadBalancerTargetGroupsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -8739,7 +8739,7 @@ pub enum AttachLoadBalancersError {
 impl AttachLoadBalancersError {
     pub fn from_body(body: &str) -> AttachLoadBalancersError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -8818,7 +8818,7 @@ pub enum CompleteLifecycleActionError {
 impl CompleteLifecycleActionError {
     pub fn from_body(body: &str) -> CompleteLifecycleActionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -8901,7 +8901,7 @@ pub enum CreateAutoScalingGroupError {
 impl CreateAutoScalingGroupError {
     pub fn from_body(body: &str) -> CreateAutoScalingGroupError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -8992,7 +8992,7 @@ pub enum CreateLaunchConfigurationError {
 impl CreateLaunchConfigurationError {
     pub fn from_body(body: &str) -> CreateLaunchConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9085,7 +9085,7 @@ pub enum CreateOrUpdateTagsError {
 impl CreateOrUpdateTagsError {
     pub fn from_body(body: &str) -> CreateOrUpdateTagsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9180,7 +9180,7 @@ pub enum DeleteAutoScalingGroupError {
 impl DeleteAutoScalingGroupError {
     pub fn from_body(body: &str) -> DeleteAutoScalingGroupError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9271,7 +9271,7 @@ pub enum DeleteLaunchConfigurationError {
 impl DeleteLaunchConfigurationError {
     pub fn from_body(body: &str) -> DeleteLaunchConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9354,7 +9354,7 @@ pub enum DeleteLifecycleHookError {
 impl DeleteLifecycleHookError {
     pub fn from_body(body: &str) -> DeleteLifecycleHookError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9433,7 +9433,7 @@ pub enum DeleteNotificationConfigurationError {
 impl DeleteNotificationConfigurationError {
     pub fn from_body(body: &str) -> DeleteNotificationConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9514,7 +9514,7 @@ pub enum DeletePolicyError {
 impl DeletePolicyError {
     pub fn from_body(body: &str) -> DeletePolicyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9591,7 +9591,7 @@ pub enum DeleteScheduledActionError {
 impl DeleteScheduledActionError {
     pub fn from_body(body: &str) -> DeleteScheduledActionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9672,7 +9672,7 @@ pub enum DeleteTagsError {
 impl DeleteTagsError {
     pub fn from_body(body: &str) -> DeleteTagsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9753,7 +9753,7 @@ pub enum DescribeAccountLimitsError {
 impl DescribeAccountLimitsError {
     pub fn from_body(body: &str) -> DescribeAccountLimitsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9832,7 +9832,7 @@ pub enum DescribeAdjustmentTypesError {
 impl DescribeAdjustmentTypesError {
     pub fn from_body(body: &str) -> DescribeAdjustmentTypesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9913,7 +9913,7 @@ pub enum DescribeAutoScalingGroupsError {
 impl DescribeAutoScalingGroupsError {
     pub fn from_body(body: &str) -> DescribeAutoScalingGroupsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9998,7 +9998,7 @@ pub enum DescribeAutoScalingInstancesError {
 impl DescribeAutoScalingInstancesError {
     pub fn from_body(body: &str) -> DescribeAutoScalingInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10083,7 +10083,7 @@ pub enum DescribeAutoScalingNotificationTypesError {
 impl DescribeAutoScalingNotificationTypesError {
     pub fn from_body(body: &str) -> DescribeAutoScalingNotificationTypesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10166,7 +10166,7 @@ pub enum DescribeLaunchConfigurationsError {
 impl DescribeLaunchConfigurationsError {
     pub fn from_body(body: &str) -> DescribeLaunchConfigurationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10251,7 +10251,7 @@ pub enum DescribeLifecycleHookTypesError {
 impl DescribeLifecycleHookTypesError {
     pub fn from_body(body: &str) -> DescribeLifecycleHookTypesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10330,7 +10330,7 @@ pub enum DescribeLifecycleHooksError {
 impl DescribeLifecycleHooksError {
     pub fn from_body(body: &str) -> DescribeLifecycleHooksError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10409,7 +10409,7 @@ pub enum DescribeLoadBalancerTargetGroupsError {
 impl DescribeLoadBalancerTargetGroupsError {
     pub fn from_body(body: &str) -> DescribeLoadBalancerTargetGroupsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10490,7 +10490,7 @@ pub enum DescribeLoadBalancersError {
 impl DescribeLoadBalancersError {
     pub fn from_body(body: &str) -> DescribeLoadBalancersError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10569,7 +10569,7 @@ pub enum DescribeMetricCollectionTypesError {
 impl DescribeMetricCollectionTypesError {
     pub fn from_body(body: &str) -> DescribeMetricCollectionTypesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10652,7 +10652,7 @@ pub enum DescribeNotificationConfigurationsError {
 impl DescribeNotificationConfigurationsError {
     pub fn from_body(body: &str) -> DescribeNotificationConfigurationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10739,7 +10739,7 @@ pub enum DescribePoliciesError {
 impl DescribePoliciesError {
     pub fn from_body(body: &str) -> DescribePoliciesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10822,7 +10822,7 @@ pub enum DescribeScalingActivitiesError {
 impl DescribeScalingActivitiesError {
     pub fn from_body(body: &str) -> DescribeScalingActivitiesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10905,7 +10905,7 @@ pub enum DescribeScalingProcessTypesError {
 impl DescribeScalingProcessTypesError {
     pub fn from_body(body: &str) -> DescribeScalingProcessTypesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10986,7 +10986,7 @@ pub enum DescribeScheduledActionsError {
 impl DescribeScheduledActionsError {
     pub fn from_body(body: &str) -> DescribeScheduledActionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11071,7 +11071,7 @@ pub enum DescribeTagsError {
 impl DescribeTagsError {
     pub fn from_body(body: &str) -> DescribeTagsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11152,7 +11152,7 @@ pub enum DescribeTerminationPolicyTypesError {
 impl DescribeTerminationPolicyTypesError {
     pub fn from_body(body: &str) -> DescribeTerminationPolicyTypesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11233,7 +11233,7 @@ pub enum DetachInstancesError {
 impl DetachInstancesError {
     pub fn from_body(body: &str) -> DetachInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11310,7 +11310,7 @@ pub enum DetachLoadBalancerTargetGroupsError {
 impl DetachLoadBalancerTargetGroupsError {
     pub fn from_body(body: &str) -> DetachLoadBalancerTargetGroupsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11391,7 +11391,7 @@ pub enum DetachLoadBalancersError {
 impl DetachLoadBalancersError {
     pub fn from_body(body: &str) -> DetachLoadBalancersError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11470,7 +11470,7 @@ pub enum DisableMetricsCollectionError {
 impl DisableMetricsCollectionError {
     pub fn from_body(body: &str) -> DisableMetricsCollectionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11549,7 +11549,7 @@ pub enum EnableMetricsCollectionError {
 impl EnableMetricsCollectionError {
     pub fn from_body(body: &str) -> EnableMetricsCollectionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11628,7 +11628,7 @@ pub enum EnterStandbyError {
 impl EnterStandbyError {
     pub fn from_body(body: &str) -> EnterStandbyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11707,7 +11707,7 @@ pub enum ExecutePolicyError {
 impl ExecutePolicyError {
     pub fn from_body(body: &str) -> ExecutePolicyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11788,7 +11788,7 @@ pub enum ExitStandbyError {
 impl ExitStandbyError {
     pub fn from_body(body: &str) -> ExitStandbyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11867,7 +11867,7 @@ pub enum PutLifecycleHookError {
 impl PutLifecycleHookError {
     pub fn from_body(body: &str) -> PutLifecycleHookError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11950,7 +11950,7 @@ pub enum PutNotificationConfigurationError {
 impl PutNotificationConfigurationError {
     pub fn from_body(body: &str) -> PutNotificationConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12037,7 +12037,7 @@ pub enum PutScalingPolicyError {
 impl PutScalingPolicyError {
     pub fn from_body(body: &str) -> PutScalingPolicyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12122,7 +12122,7 @@ pub enum PutScheduledUpdateGroupActionError {
 impl PutScheduledUpdateGroupActionError {
     pub fn from_body(body: &str) -> PutScheduledUpdateGroupActionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12211,7 +12211,7 @@ pub enum RecordLifecycleActionHeartbeatError {
 impl RecordLifecycleActionHeartbeatError {
     pub fn from_body(body: &str) -> RecordLifecycleActionHeartbeatError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12294,7 +12294,7 @@ pub enum ResumeProcessesError {
 impl ResumeProcessesError {
     pub fn from_body(body: &str) -> ResumeProcessesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12377,7 +12377,7 @@ pub enum SetDesiredCapacityError {
 impl SetDesiredCapacityError {
     pub fn from_body(body: &str) -> SetDesiredCapacityError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12462,7 +12462,7 @@ pub enum SetInstanceHealthError {
 impl SetInstanceHealthError {
     pub fn from_body(body: &str) -> SetInstanceHealthError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12543,7 +12543,7 @@ pub enum SetInstanceProtectionError {
 impl SetInstanceProtectionError {
     pub fn from_body(body: &str) -> SetInstanceProtectionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12628,7 +12628,7 @@ pub enum SuspendProcessesError {
 impl SuspendProcessesError {
     pub fn from_body(body: &str) -> SuspendProcessesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12711,7 +12711,7 @@ pub enum TerminateInstanceInAutoScalingGroupError {
 impl TerminateInstanceInAutoScalingGroupError {
     pub fn from_body(body: &str) -> TerminateInstanceInAutoScalingGroupError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12802,7 +12802,7 @@ pub enum UpdateAutoScalingGroupError {
 impl UpdateAutoScalingGroupError {
     pub fn from_body(body: &str) -> UpdateAutoScalingGroupError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13274,8 +13274,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(
@@ -13327,8 +13326,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(AttachLoadBalancersResultTypeDeserializer::deserialize(
@@ -13378,8 +13376,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(CompleteLifecycleActionAnswerDeserializer::deserialize(
@@ -13569,8 +13566,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DeleteLifecycleHookAnswerDeserializer::deserialize(
@@ -13725,8 +13721,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeAccountLimitsAnswerDeserializer::deserialize(
@@ -13775,8 +13770,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeAdjustmentTypesAnswerDeserializer::deserialize(
@@ -13826,8 +13820,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(AutoScalingGroupsTypeDeserializer::deserialize(
@@ -13877,8 +13870,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(AutoScalingInstancesTypeDeserializer::deserialize(
@@ -13930,8 +13922,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(
@@ -13983,8 +13974,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(LaunchConfigurationsTypeDeserializer::deserialize(
@@ -14033,8 +14023,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeLifecycleHookTypesAnswerDeserializer::deserialize(
@@ -14084,8 +14073,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeLifecycleHooksAnswerDeserializer::deserialize(
@@ -14136,8 +14124,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(
@@ -14189,8 +14176,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeLoadBalancersResponseDeserializer::deserialize(
@@ -14239,8 +14225,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(
@@ -14295,8 +14280,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(
@@ -14348,8 +14332,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(PoliciesTypeDeserializer::deserialize(
@@ -14399,8 +14382,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ActivitiesTypeDeserializer::deserialize(
@@ -14449,8 +14431,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ProcessesTypeDeserializer::deserialize(
@@ -14500,8 +14481,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ScheduledActionsTypeDeserializer::deserialize(
@@ -14548,8 +14528,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(TagsTypeDeserializer::deserialize(
@@ -14599,8 +14578,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(
@@ -14652,8 +14630,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DetachInstancesAnswerDeserializer::deserialize(
@@ -14704,8 +14681,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(
@@ -14757,8 +14733,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DetachLoadBalancersResultTypeDeserializer::deserialize(
@@ -14864,8 +14839,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(EnterStandbyAnswerDeserializer::deserialize(
@@ -14940,8 +14914,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ExitStandbyAnswerDeserializer::deserialize(
@@ -14991,8 +14964,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(PutLifecycleHookAnswerDeserializer::deserialize(
@@ -15070,8 +15042,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(PolicyARNTypeDeserializer::deserialize(
@@ -15150,8 +15121,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(
@@ -15287,8 +15257,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(SetInstanceProtectionAnswerDeserializer::deserialize(
@@ -15366,8 +15335,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ActivityTypeDeserializer::deserialize(
@@ -8981,7 +8981,7 @@ pub enum CancelUpdateStackError {
 impl CancelUpdateStackError {
     pub fn from_body(body: &str) -> CancelUpdateStackError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9060,7 +9060,7 @@ pub enum ContinueUpdateRollbackError {
 impl ContinueUpdateRollbackError {
     pub fn from_body(body: &str) -> ContinueUpdateRollbackError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9143,7 +9143,7 @@ pub enum CreateChangeSetError {
 impl CreateChangeSetError {
     pub fn from_body(body: &str) -> CreateChangeSetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9236,7 +9236,7 @@ pub enum CreateStackError {
 impl CreateStackError {
     pub fn from_body(body: &str) -> CreateStackError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9335,7 +9335,7 @@ pub enum CreateStackInstancesError {
 impl CreateStackInstancesError {
     pub fn from_body(body: &str) -> CreateStackInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9440,7 +9440,7 @@ pub enum CreateStackSetError {
 impl CreateStackSetError {
     pub fn from_body(body: &str) -> CreateStackSetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9525,7 +9525,7 @@ pub enum DeleteChangeSetError {
 impl DeleteChangeSetError {
     pub fn from_body(body: &str) -> DeleteChangeSetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9602,7 +9602,7 @@ pub enum DeleteStackError {
 impl DeleteStackError {
     pub fn from_body(body: &str) -> DeleteStackError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9687,7 +9687,7 @@ pub enum DeleteStackInstancesError {
 impl DeleteStackInstancesError {
     pub fn from_body(body: &str) -> DeleteStackInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9786,7 +9786,7 @@ pub enum DeleteStackSetError {
 impl DeleteStackSetError {
     pub fn from_body(body: &str) -> DeleteStackSetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9865,7 +9865,7 @@ pub enum DescribeAccountLimitsError {
 impl DescribeAccountLimitsError {
     pub fn from_body(body: &str) -> DescribeAccountLimitsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9940,7 +9940,7 @@ pub enum DescribeChangeSetError {
 impl DescribeChangeSetError {
     pub fn from_body(body: &str) -> DescribeChangeSetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10017,7 +10017,7 @@ pub enum DescribeStackEventsError {
 impl DescribeStackEventsError {
     pub fn from_body(body: &str) -> DescribeStackEventsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10094,7 +10094,7 @@ pub enum DescribeStackInstanceError {
 impl DescribeStackInstanceError {
     pub fn from_body(body: &str) -> DescribeStackInstanceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10177,7 +10177,7 @@ pub enum DescribeStackResourceError {
 impl DescribeStackResourceError {
     pub fn from_body(body: &str) -> DescribeStackResourceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10250,7 +10250,7 @@ pub enum DescribeStackResourcesError {
 impl DescribeStackResourcesError {
     pub fn from_body(body: &str) -> DescribeStackResourcesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10325,7 +10325,7 @@ pub enum DescribeStackSetError {
 impl DescribeStackSetError {
     pub fn from_body(body: &str) -> DescribeStackSetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10404,7 +10404,7 @@ pub enum DescribeStackSetOperationError {
 impl DescribeStackSetOperationError {
     pub fn from_body(body: &str) -> DescribeStackSetOperationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10485,7 +10485,7 @@ pub enum DescribeStacksError {
 impl DescribeStacksError {
     pub fn from_body(body: &str) -> DescribeStacksError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10556,7 +10556,7 @@ pub enum EstimateTemplateCostError {
 impl EstimateTemplateCostError {
     pub fn from_body(body: &str) -> EstimateTemplateCostError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10637,7 +10637,7 @@ pub enum ExecuteChangeSetError {
 impl ExecuteChangeSetError {
     pub fn from_body(body: &str) -> ExecuteChangeSetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10726,7 +10726,7 @@ pub enum GetStackPolicyError {
 impl GetStackPolicyError {
     pub fn from_body(body: &str) -> GetStackPolicyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10799,7 +10799,7 @@ pub enum GetTemplateError {
 impl GetTemplateError {
     pub fn from_body(body: &str) -> GetTemplateError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10876,7 +10876,7 @@ pub enum GetTemplateSummaryError {
 impl GetTemplateSummaryError {
     pub fn from_body(body: &str) -> GetTemplateSummaryError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10953,7 +10953,7 @@ pub enum ListChangeSetsError {
 impl ListChangeSetsError {
     pub fn from_body(body: &str) -> ListChangeSetsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11024,7 +11024,7 @@ pub enum ListExportsError {
 impl ListExportsError {
     pub fn from_body(body: &str) -> ListExportsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11095,7 +11095,7 @@ pub enum ListImportsError {
 impl ListImportsError {
     pub fn from_body(body: &str) -> ListImportsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11168,7 +11168,7 @@ pub enum ListStackInstancesError {
 impl ListStackInstancesError {
     pub fn from_body(body: &str) -> ListStackInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11245,7 +11245,7 @@ pub enum ListStackResourcesError {
 impl ListStackResourcesError {
     pub fn from_body(body: &str) -> ListStackResourcesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11322,7 +11322,7 @@ pub enum ListStackSetOperationResultsError {
 impl ListStackSetOperationResultsError {
     pub fn from_body(body: &str) -> ListStackSetOperationResultsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11409,7 +11409,7 @@ pub enum ListStackSetOperationsError {
 impl ListStackSetOperationsError {
     pub fn from_body(body: &str) -> ListStackSetOperationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11486,7 +11486,7 @@ pub enum ListStackSetsError {
 impl ListStackSetsError {
     pub fn from_body(body: &str) -> ListStackSetsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11557,7 +11557,7 @@ pub enum ListStacksError {
 impl ListStacksError {
     pub fn from_body(body: &str) -> ListStacksError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11628,7 +11628,7 @@ pub enum SetStackPolicyError {
 impl SetStackPolicyError {
     pub fn from_body(body: &str) -> SetStackPolicyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11699,7 +11699,7 @@ pub enum SignalResourceError {
 impl SignalResourceError {
     pub fn from_body(body: &str) -> SignalResourceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11776,7 +11776,7 @@ pub enum StopStackSetOperationError {
 impl StopStackSetOperationError {
     pub fn from_body(body: &str) -> StopStackSetOperationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11865,7 +11865,7 @@ pub enum UpdateStackError {
 impl UpdateStackError {
     pub fn from_body(body: &str) -> UpdateStackError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11956,7 +11956,7 @@ pub enum UpdateStackInstancesError {
 impl UpdateStackInstancesError {
     pub fn from_body(body: &str) -> UpdateStackInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12067,7 +12067,7 @@ pub enum UpdateStackSetError {
 impl UpdateStackSetError {
     pub fn from_body(body: &str) -> UpdateStackSetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12160,7 +12160,7 @@ pub enum UpdateTerminationProtectionError {
 impl UpdateTerminationProtectionError {
     pub fn from_body(body: &str) -> UpdateTerminationProtectionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12233,7 +12233,7 @@ pub enum ValidateTemplateError {
 impl ValidateTemplateError {
     pub fn from_body(body: &str) -> ValidateTemplateError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12634,8 +12634,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ContinueUpdateRollbackOutputDeserializer::deserialize(
@@ -12685,8 +12684,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(CreateChangeSetOutputDeserializer::deserialize(
@@ -12736,8 +12734,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(CreateStackOutputDeserializer::deserialize(
@@ -12787,8 +12784,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(CreateStackInstancesOutputDeserializer::deserialize(
@@ -12838,8 +12834,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(CreateStackSetOutputDeserializer::deserialize(
@@ -12889,8 +12884,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DeleteChangeSetOutputDeserializer::deserialize(
@@ -12965,8 +12959,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DeleteStackInstancesOutputDeserializer::deserialize(
@@ -13016,8 +13009,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DeleteStackSetOutputDeserializer::deserialize(
@@ -13067,8 +13059,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeAccountLimitsOutputDeserializer::deserialize(
@@ -13118,8 +13109,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeChangeSetOutputDeserializer::deserialize(
@@ -13169,8 +13159,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeStackEventsOutputDeserializer::deserialize(
@@ -13220,8 +13209,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeStackInstanceOutputDeserializer::deserialize(
@@ -13271,8 +13259,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeStackResourceOutputDeserializer::deserialize(
@@ -13322,8 +13309,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeStackResourcesOutputDeserializer::deserialize(
@@ -13373,8 +13359,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeStackSetOutputDeserializer::deserialize(
@@ -13424,8 +13409,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeStackSetOperationOutputDeserializer::deserialize(
@@ -13475,8 +13459,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeStacksOutputDeserializer::deserialize(
@@ -13526,8 +13509,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(EstimateTemplateCostOutputDeserializer::deserialize(
@@ -13577,8 +13559,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ExecuteChangeSetOutputDeserializer::deserialize(
@@ -13628,8 +13609,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(GetStackPolicyOutputDeserializer::deserialize(
@@ -13679,8 +13659,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(GetTemplateOutputDeserializer::deserialize(
@@ -13730,8 +13709,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(GetTemplateSummaryOutputDeserializer::deserialize(
@@ -13781,8 +13759,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ListChangeSetsOutputDeserializer::deserialize(
@@ -13832,8 +13809,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ListExportsOutputDeserializer::deserialize(
@@ -13883,8 +13859,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ListImportsOutputDeserializer::deserialize(
@@ -13934,8 +13909,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ListStackInstancesOutputDeserializer::deserialize(
@@ -13985,8 +13959,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ListStackResourcesOutputDeserializer::deserialize(
@@ -14036,8 +14009,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(
@@ -14089,8 +14061,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ListStackSetOperationsOutputDeserializer::deserialize(
@@ -14140,8 +14111,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ListStackSetsOutputDeserializer::deserialize(
@@ -14191,8 +14161,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ListStacksOutputDeserializer::deserialize(
@@ -14298,8 +14267,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(StopStackSetOperationOutputDeserializer::deserialize(
@@ -14349,8 +14317,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(UpdateStackOutputDeserializer::deserialize(
@@ -14400,8 +14367,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(UpdateStackInstancesOutputDeserializer::deserialize(
@@ -14451,8 +14417,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(UpdateStackSetOutputDeserializer::deserialize(
@@ -14502,8 +14467,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(UpdateTerminationProtectionOutputDeserializer::deserialize(
@@ -14553,8 +14517,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ValidateTemplateOutputDeserializer::deserialize(
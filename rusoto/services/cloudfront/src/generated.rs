@@ -8117,7 +8117,7 @@ pub enum CreateCloudFrontOriginAccessIdentityError {
 impl CreateCloudFrontOriginAccessIdentityError {
     pub fn from_body(body: &str) -> CreateCloudFrontOriginAccessIdentityError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
                             Ok(parsed_error) => {
@@ -8275,7 +8275,7 @@ pub enum CreateDistributionError {
 impl CreateDistributionError {
     pub fn from_body(body: &str) -> CreateDistributionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -8614,7 +8614,7 @@ pub enum CreateDistributionWithTagsError {
 impl CreateDistributionWithTagsError {
     pub fn from_body(body: &str) -> CreateDistributionWithTagsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -8911,7 +8911,7 @@ pub enum CreateInvalidationError {
 impl CreateInvalidationError {
     pub fn from_body(body: &str) -> CreateInvalidationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9038,7 +9038,7 @@ pub enum CreateStreamingDistributionError {
 impl CreateStreamingDistributionError {
     pub fn from_body(body: &str) -> CreateStreamingDistributionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9203,7 +9203,7 @@ pub enum CreateStreamingDistributionWithTagsError {
 impl CreateStreamingDistributionWithTagsError {
     pub fn from_body(body: &str) -> CreateStreamingDistributionWithTagsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9362,7 +9362,7 @@ pub enum DeleteCloudFrontOriginAccessIdentityError {
 impl DeleteCloudFrontOriginAccessIdentityError {
     pub fn from_body(body: &str) -> DeleteCloudFrontOriginAccessIdentityError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
                             Ok(parsed_error) => {
@@ -9456,7 +9456,7 @@ pub enum DeleteDistributionError {
 impl DeleteDistributionError {
     pub fn from_body(body: &str) -> DeleteDistributionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9557,7 +9557,7 @@ pub enum DeleteServiceLinkedRoleError {
 impl DeleteServiceLinkedRoleError {
     pub fn from_body(body: &str) -> DeleteServiceLinkedRoleError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9656,7 +9656,7 @@ pub enum DeleteStreamingDistributionError {
 impl DeleteStreamingDistributionError {
     pub fn from_body(body: &str) -> DeleteStreamingDistributionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9759,7 +9759,7 @@ pub enum GetCloudFrontOriginAccessIdentityError {
 impl GetCloudFrontOriginAccessIdentityError {
     pub fn from_body(body: &str) -> GetCloudFrontOriginAccessIdentityError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -9848,7 +9848,7 @@ pub enum GetCloudFrontOriginAccessIdentityConfigError {
 impl GetCloudFrontOriginAccessIdentityConfigError {
     pub fn from_body(body: &str) -> GetCloudFrontOriginAccessIdentityConfigError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
                             Ok(parsed_error) => {
@@ -9927,7 +9927,7 @@ pub enum GetDistributionError {
 impl GetDistributionError {
     pub fn from_body(body: &str) -> GetDistributionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10010,7 +10010,7 @@ pub enum GetDistributionConfigError {
 impl GetDistributionConfigError {
     pub fn from_body(body: &str) -> GetDistributionConfigError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10097,7 +10097,7 @@ pub enum GetInvalidationError {
 impl GetInvalidationError {
     pub fn from_body(body: &str) -> GetInvalidationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10184,7 +10184,7 @@ pub enum GetStreamingDistributionError {
 impl GetStreamingDistributionError {
     pub fn from_body(body: &str) -> GetStreamingDistributionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10271,7 +10271,7 @@ pub enum GetStreamingDistributionConfigError {
 impl GetStreamingDistributionConfigError {
     pub fn from_body(body: &str) -> GetStreamingDistributionConfigError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10356,7 +10356,7 @@ pub enum ListCloudFrontOriginAccessIdentitiesError {
 impl ListCloudFrontOriginAccessIdentitiesError {
     pub fn from_body(body: &str) -> ListCloudFrontOriginAccessIdentitiesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10435,7 +10435,7 @@ pub enum ListDistributionsError {
 impl ListDistributionsError {
     pub fn from_body(body: &str) -> ListDistributionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10516,7 +10516,7 @@ pub enum ListDistributionsByWebACLIdError {
 impl ListDistributionsByWebACLIdError {
     pub fn from_body(body: &str) -> ListDistributionsByWebACLIdError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10603,7 +10603,7 @@ pub enum ListInvalidationsError {
 impl ListInvalidationsError {
     pub fn from_body(body: &str) -> ListInvalidationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10690,7 +10690,7 @@ pub enum ListStreamingDistributionsError {
 impl ListStreamingDistributionsError {
     pub fn from_body(body: &str) -> ListStreamingDistributionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10775,7 +10775,7 @@ pub enum ListTagsForResourceError {
 impl ListTagsForResourceError {
     pub fn from_body(body: &str) -> ListTagsForResourceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10872,7 +10872,7 @@ pub enum TagResourceError {
 impl TagResourceError {
     pub fn from_body(body: &str) -> TagResourceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10967,7 +10967,7 @@ pub enum UntagResourceError {
 impl UntagResourceError {
     pub fn from_body(body: &str) -> UntagResourceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11070,7 +11070,7 @@ pub enum UpdateCloudFrontOriginAccessIdentityError {
 impl UpdateCloudFrontOriginAccessIdentityError {
     pub fn from_body(body: &str) -> UpdateCloudFrontOriginAccessIdentityError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
                             Ok(parsed_error) => {
@@ -11235,7 +11235,7 @@ pub enum UpdateDistributionError {
 impl UpdateDistributionError {
     pub fn from_body(body: &str) -> UpdateDistributionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11518,7 +11518,7 @@ pub enum UpdateStreamingDistributionError {
 impl UpdateStreamingDistributionError {
     pub fn from_body(body: &str) -> UpdateStreamingDistributionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11909,8 +11909,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CreateCloudFrontOriginAccessIdentityResultDeserializer::deserialize(
@@ -11977,8 +11976,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateDistributionResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -12046,8 +12044,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateDistributionWithTagsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -12115,8 +12112,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateInvalidationResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -12177,8 +12173,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateStreamingDistributionResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -12249,8 +12244,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CreateStreamingDistributionWithTagsResultDeserializer::deserialize(
@@ -12435,8 +12429,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetCloudFrontOriginAccessIdentityResultDeserializer::deserialize(
@@ -12494,8 +12487,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetCloudFrontOriginAccessIdentityConfigResultDeserializer::deserialize(
@@ -12547,8 +12539,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetDistributionResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -12598,8 +12589,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetDistributionConfigResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -12653,8 +12643,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetInvalidationResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -12700,8 +12689,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetStreamingDistributionResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -12755,8 +12743,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetStreamingDistributionConfigResultDeserializer::deserialize(
@@ -12820,8 +12807,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ListCloudFrontOriginAccessIdentitiesResultDeserializer::deserialize(
@@ -12878,8 +12864,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListDistributionsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -12937,8 +12922,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListDistributionsByWebACLIdResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -12996,8 +12980,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListInvalidationsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -13052,8 +13035,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListStreamingDistributionsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -13103,8 +13085,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListTagsForResourceResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -13241,8 +13222,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         UpdateCloudFrontOriginAccessIdentityResultDeserializer::deserialize(
@@ -13309,8 +13289,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(UpdateDistributionResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -13378,8 +13357,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(UpdateStreamingDistributionResultDeserializer::deserialize(
                         &actual_tag_name,
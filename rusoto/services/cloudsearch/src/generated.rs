@@ -5493,7 +5493,7 @@ pub enum BuildSuggestersError {
 impl BuildSuggestersError {
     pub fn from_body(body: &str) -> BuildSuggestersError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -5580,7 +5580,7 @@ pub enum CreateDomainError {
 impl CreateDomainError {
     pub fn from_body(body: &str) -> CreateDomainError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -5671,7 +5671,7 @@ pub enum DefineAnalysisSchemeError {
 impl DefineAnalysisSchemeError {
     pub fn from_body(body: &str) -> DefineAnalysisSchemeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -5774,7 +5774,7 @@ pub enum DefineExpressionError {
 impl DefineExpressionError {
     pub fn from_body(body: &str) -> DefineExpressionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -5873,7 +5873,7 @@ pub enum DefineIndexFieldError {
 impl DefineIndexFieldError {
     pub fn from_body(body: &str) -> DefineIndexFieldError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -5972,7 +5972,7 @@ pub enum DefineSuggesterError {
 impl DefineSuggesterError {
     pub fn from_body(body: &str) -> DefineSuggesterError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -6069,7 +6069,7 @@ pub enum DeleteAnalysisSchemeError {
 impl DeleteAnalysisSchemeError {
     pub fn from_body(body: &str) -> DeleteAnalysisSchemeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -6162,7 +6162,7 @@ pub enum DeleteDomainError {
 impl DeleteDomainError {
     pub fn from_body(body: &str) -> DeleteDomainError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -6247,7 +6247,7 @@ pub enum DeleteExpressionError {
 impl DeleteExpressionError {
     pub fn from_body(body: &str) -> DeleteExpressionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -6340,7 +6340,7 @@ pub enum DeleteIndexFieldError {
 impl DeleteIndexFieldError {
     pub fn from_body(body: &str) -> DeleteIndexFieldError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -6433,7 +6433,7 @@ pub enum DeleteSuggesterError {
 impl DeleteSuggesterError {
     pub fn from_body(body: &str) -> DeleteSuggesterError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -6524,7 +6524,7 @@ pub enum DescribeAnalysisSchemesError {
 impl DescribeAnalysisSchemesError {
     pub fn from_body(body: &str) -> DescribeAnalysisSchemesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -6621,7 +6621,7 @@ pub enum DescribeAvailabilityOptionsError {
 impl DescribeAvailabilityOptionsError {
     pub fn from_body(body: &str) -> DescribeAvailabilityOptionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -6722,7 +6722,7 @@ pub enum DescribeDomainsError {
 impl DescribeDomainsError {
     pub fn from_body(body: &str) -> DescribeDomainsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -6805,7 +6805,7 @@ pub enum DescribeExpressionsError {
 impl DescribeExpressionsError {
     pub fn from_body(body: &str) -> DescribeExpressionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -6896,7 +6896,7 @@ pub enum DescribeIndexFieldsError {
 impl DescribeIndexFieldsError {
     pub fn from_body(body: &str) -> DescribeIndexFieldsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -6987,7 +6987,7 @@ pub enum DescribeScalingParametersError {
 impl DescribeScalingParametersError {
     pub fn from_body(body: &str) -> DescribeScalingParametersError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -7078,7 +7078,7 @@ pub enum DescribeServiceAccessPoliciesError {
 impl DescribeServiceAccessPoliciesError {
     pub fn from_body(body: &str) -> DescribeServiceAccessPoliciesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -7169,7 +7169,7 @@ pub enum DescribeSuggestersError {
 impl DescribeSuggestersError {
     pub fn from_body(body: &str) -> DescribeSuggestersError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -7260,7 +7260,7 @@ pub enum IndexDocumentsError {
 impl IndexDocumentsError {
     pub fn from_body(body: &str) -> IndexDocumentsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -7343,7 +7343,7 @@ pub enum ListDomainNamesError {
 impl ListDomainNamesError {
     pub fn from_body(body: &str) -> ListDomainNamesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -7428,7 +7428,7 @@ pub enum UpdateAvailabilityOptionsError {
 impl UpdateAvailabilityOptionsError {
     pub fn from_body(body: &str) -> UpdateAvailabilityOptionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -7535,7 +7535,7 @@ pub enum UpdateScalingParametersError {
 impl UpdateScalingParametersError {
     pub fn from_body(body: &str) -> UpdateScalingParametersError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -7638,7 +7638,7 @@ pub enum UpdateServiceAccessPoliciesError {
 impl UpdateServiceAccessPoliciesError {
     pub fn from_body(body: &str) -> UpdateServiceAccessPoliciesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -7933,8 +7933,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(BuildSuggestersResponseDeserializer::deserialize(
@@ -7984,8 +7983,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(CreateDomainResponseDeserializer::deserialize(
@@ -8035,8 +8033,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DefineAnalysisSchemeResponseDeserializer::deserialize(
@@ -8086,8 +8083,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DefineExpressionResponseDeserializer::deserialize(
@@ -8137,8 +8133,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DefineIndexFieldResponseDeserializer::deserialize(
@@ -8188,8 +8183,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DefineSuggesterResponseDeserializer::deserialize(
@@ -8239,8 +8233,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DeleteAnalysisSchemeResponseDeserializer::deserialize(
@@ -8290,8 +8283,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DeleteDomainResponseDeserializer::deserialize(
@@ -8341,8 +8333,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DeleteExpressionResponseDeserializer::deserialize(
@@ -8392,8 +8383,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DeleteIndexFieldResponseDeserializer::deserialize(
@@ -8443,8 +8433,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DeleteSuggesterResponseDeserializer::deserialize(
@@ -8494,8 +8483,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeAnalysisSchemesResponseDeserializer::deserialize(
@@ -8545,8 +8533,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(
@@ -8598,8 +8585,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeDomainsResponseDeserializer::deserialize(
@@ -8649,8 +8635,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeExpressionsResponseDeserializer::deserialize(
@@ -8700,8 +8685,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeIndexFieldsResponseDeserializer::deserialize(
@@ -8751,8 +8735,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeScalingParametersResponseDeserializer::deserialize(
@@ -8803,8 +8786,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(
@@ -8856,8 +8838,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeSuggestersResponseDeserializer::deserialize(
@@ -8907,8 +8888,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(IndexDocumentsResponseDeserializer::deserialize(
@@ -8955,8 +8935,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ListDomainNamesResponseDeserializer::deserialize(
@@ -9006,8 +8985,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(UpdateAvailabilityOptionsResponseDeserializer::deserialize(
@@ -9057,8 +9035,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(UpdateScalingParametersResponseDeserializer::deserialize(
@@ -9108,8 +9085,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(
@@ -2983,7 +2983,7 @@ pub enum DeleteAlarmsError {
 impl DeleteAlarmsError {
     pub fn from_body(body: &str) -> DeleteAlarmsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -3064,7 +3064,7 @@ pub enum DeleteDashboardsError {
 impl DeleteDashboardsError {
     pub fn from_body(body: &str) -> DeleteDashboardsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -3149,7 +3149,7 @@ pub enum DescribeAlarmHistoryError {
 impl DescribeAlarmHistoryError {
     pub fn from_body(body: &str) -> DescribeAlarmHistoryError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -3228,7 +3228,7 @@ pub enum DescribeAlarmsError {
 impl DescribeAlarmsError {
     pub fn from_body(body: &str) -> DescribeAlarmsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -3303,7 +3303,7 @@ pub enum DescribeAlarmsForMetricError {
 impl DescribeAlarmsForMetricError {
     pub fn from_body(body: &str) -> DescribeAlarmsForMetricError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -3376,7 +3376,7 @@ pub enum DisableAlarmActionsError {
 impl DisableAlarmActionsError {
     pub fn from_body(body: &str) -> DisableAlarmActionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -3449,7 +3449,7 @@ pub enum EnableAlarmActionsError {
 impl EnableAlarmActionsError {
     pub fn from_body(body: &str) -> EnableAlarmActionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -3528,7 +3528,7 @@ pub enum GetDashboardError {
 impl GetDashboardError {
     pub fn from_body(body: &str) -> GetDashboardError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -3619,7 +3619,7 @@ pub enum GetMetricStatisticsError {
 impl GetMetricStatisticsError {
     pub fn from_body(body: &str) -> GetMetricStatisticsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -3714,7 +3714,7 @@ pub enum ListDashboardsError {
 impl ListDashboardsError {
     pub fn from_body(body: &str) -> ListDashboardsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -3797,7 +3797,7 @@ pub enum ListMetricsError {
 impl ListMetricsError {
     pub fn from_body(body: &str) -> ListMetricsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -3880,7 +3880,7 @@ pub enum PutDashboardError {
 impl PutDashboardError {
     pub fn from_body(body: &str) -> PutDashboardError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -3961,7 +3961,7 @@ pub enum PutMetricAlarmError {
 impl PutMetricAlarmError {
     pub fn from_body(body: &str) -> PutMetricAlarmError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -4044,7 +4044,7 @@ pub enum PutMetricDataError {
 impl PutMetricDataError {
     pub fn from_body(body: &str) -> PutMetricDataError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -4135,7 +4135,7 @@ pub enum SetAlarmStateError {
 impl SetAlarmStateError {
     pub fn from_body(body: &str) -> SetAlarmStateError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -4381,8 +4381,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DeleteDashboardsOutputDeserializer::deserialize(
@@ -4432,8 +4431,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeAlarmHistoryOutputDeserializer::deserialize(
@@ -4483,8 +4481,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeAlarmsOutputDeserializer::deserialize(
@@ -4534,8 +4531,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(DescribeAlarmsForMetricOutputDeserializer::deserialize(
@@ -4641,8 +4637,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(GetDashboardOutputDeserializer::deserialize(
@@ -4692,8 +4687,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(GetMetricStatisticsOutputDeserializer::deserialize(
@@ -4743,8 +4737,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ListDashboardsOutputDeserializer::deserialize(
@@ -4794,8 +4787,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(ListMetricsOutputDeserializer::deserialize(
@@ -4845,8 +4837,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     try!(start_element(&actual_tag_name, &mut stack));
                     result = try!(PutDashboardOutputDeserializer::deserialize(
@@ -54231,7 +54231,7 @@ pub enum AcceptReservedInstancesExchangeQuoteError {
 impl AcceptReservedInstancesExchangeQuoteError {
     pub fn from_body(body: &str) -> AcceptReservedInstancesExchangeQuoteError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -54305,7 +54305,7 @@ pub enum AcceptVpcEndpointConnectionsError {
 impl AcceptVpcEndpointConnectionsError {
     pub fn from_body(body: &str) -> AcceptVpcEndpointConnectionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -54379,7 +54379,7 @@ pub enum AcceptVpcPeeringConnectionError {
 impl AcceptVpcPeeringConnectionError {
     pub fn from_body(body: &str) -> AcceptVpcPeeringConnectionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -54453,7 +54453,7 @@ pub enum AllocateAddressError {
 impl AllocateAddressError {
     pub fn from_body(body: &str) -> AllocateAddressError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -54525,7 +54525,7 @@ pub enum AllocateHostsError {
 impl AllocateHostsError {
     pub fn from_body(body: &str) -> AllocateHostsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -54597,7 +54597,7 @@ pub enum AssignIpv6AddressesError {
 impl AssignIpv6AddressesError {
     pub fn from_body(body: &str) -> AssignIpv6AddressesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -54671,7 +54671,7 @@ pub enum AssignPrivateIpAddressesError {
 impl AssignPrivateIpAddressesError {
     pub fn from_body(body: &str) -> AssignPrivateIpAddressesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -54745,7 +54745,7 @@ pub enum AssociateAddressError {
 impl AssociateAddressError {
     pub fn from_body(body: &str) -> AssociateAddressError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -54817,7 +54817,7 @@ pub enum AssociateDhcpOptionsError {
 impl AssociateDhcpOptionsError {
     pub fn from_body(body: &str) -> AssociateDhcpOptionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -54891,7 +54891,7 @@ pub enum AssociateIamInstanceProfileError {
 impl AssociateIamInstanceProfileError {
     pub fn from_body(body: &str) -> AssociateIamInstanceProfileError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -54965,7 +54965,7 @@ pub enum AssociateRouteTableError {
 impl AssociateRouteTableError {
     pub fn from_body(body: &str) -> AssociateRouteTableError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55039,7 +55039,7 @@ pub enum AssociateSubnetCidrBlockError {
 impl AssociateSubnetCidrBlockError {
     pub fn from_body(body: &str) -> AssociateSubnetCidrBlockError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55113,7 +55113,7 @@ pub enum AssociateVpcCidrBlockError {
 impl AssociateVpcCidrBlockError {
     pub fn from_body(body: &str) -> AssociateVpcCidrBlockError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55187,7 +55187,7 @@ pub enum AttachClassicLinkVpcError {
 impl AttachClassicLinkVpcError {
     pub fn from_body(body: &str) -> AttachClassicLinkVpcError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55261,7 +55261,7 @@ pub enum AttachInternetGatewayError {
 impl AttachInternetGatewayError {
     pub fn from_body(body: &str) -> AttachInternetGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55335,7 +55335,7 @@ pub enum AttachNetworkInterfaceError {
 impl AttachNetworkInterfaceError {
     pub fn from_body(body: &str) -> AttachNetworkInterfaceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55409,7 +55409,7 @@ pub enum AttachVolumeError {
 impl AttachVolumeError {
     pub fn from_body(body: &str) -> AttachVolumeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55481,7 +55481,7 @@ pub enum AttachVpnGatewayError {
 impl AttachVpnGatewayError {
     pub fn from_body(body: &str) -> AttachVpnGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55553,7 +55553,7 @@ pub enum AuthorizeSecurityGroupEgressError {
 impl AuthorizeSecurityGroupEgressError {
     pub fn from_body(body: &str) -> AuthorizeSecurityGroupEgressError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55627,7 +55627,7 @@ pub enum AuthorizeSecurityGroupIngressError {
 impl AuthorizeSecurityGroupIngressError {
     pub fn from_body(body: &str) -> AuthorizeSecurityGroupIngressError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55701,7 +55701,7 @@ pub enum BundleInstanceError {
 impl BundleInstanceError {
     pub fn from_body(body: &str) -> BundleInstanceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55773,7 +55773,7 @@ pub enum CancelBundleTaskError {
 impl CancelBundleTaskError {
     pub fn from_body(body: &str) -> CancelBundleTaskError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55845,7 +55845,7 @@ pub enum CancelConversionTaskError {
 impl CancelConversionTaskError {
     pub fn from_body(body: &str) -> CancelConversionTaskError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55919,7 +55919,7 @@ pub enum CancelExportTaskError {
 impl CancelExportTaskError {
     pub fn from_body(body: &str) -> CancelExportTaskError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -55991,7 +55991,7 @@ pub enum CancelImportTaskError {
 impl CancelImportTaskError {
     pub fn from_body(body: &str) -> CancelImportTaskError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56063,7 +56063,7 @@ pub enum CancelReservedInstancesListingError {
 impl CancelReservedInstancesListingError {
     pub fn from_body(body: &str) -> CancelReservedInstancesListingError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56137,7 +56137,7 @@ pub enum EC2CancelSpotFleetRequestsError {
 impl EC2CancelSpotFleetRequestsError {
     pub fn from_body(body: &str) -> EC2CancelSpotFleetRequestsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56211,7 +56211,7 @@ pub enum CancelSpotInstanceRequestsError {
 impl CancelSpotInstanceRequestsError {
     pub fn from_body(body: &str) -> CancelSpotInstanceRequestsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56285,7 +56285,7 @@ pub enum ConfirmProductInstanceError {
 impl ConfirmProductInstanceError {
     pub fn from_body(body: &str) -> ConfirmProductInstanceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56359,7 +56359,7 @@ pub enum CopyFpgaImageError {
 impl CopyFpgaImageError {
     pub fn from_body(body: &str) -> CopyFpgaImageError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56431,7 +56431,7 @@ pub enum CopyImageError {
 impl CopyImageError {
     pub fn from_body(body: &str) -> CopyImageError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56503,7 +56503,7 @@ pub enum CopySnapshotError {
 impl CopySnapshotError {
     pub fn from_body(body: &str) -> CopySnapshotError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56575,7 +56575,7 @@ pub enum CreateCustomerGatewayError {
 impl CreateCustomerGatewayError {
     pub fn from_body(body: &str) -> CreateCustomerGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56649,7 +56649,7 @@ pub enum CreateDefaultSubnetError {
 impl CreateDefaultSubnetError {
     pub fn from_body(body: &str) -> CreateDefaultSubnetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56723,7 +56723,7 @@ pub enum CreateDefaultVpcError {
 impl CreateDefaultVpcError {
     pub fn from_body(body: &str) -> CreateDefaultVpcError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56795,7 +56795,7 @@ pub enum CreateDhcpOptionsError {
 impl CreateDhcpOptionsError {
     pub fn from_body(body: &str) -> CreateDhcpOptionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56869,7 +56869,7 @@ pub enum CreateEgressOnlyInternetGatewayError {
 impl CreateEgressOnlyInternetGatewayError {
     pub fn from_body(body: &str) -> CreateEgressOnlyInternetGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -56943,7 +56943,7 @@ pub enum CreateFlowLogsError {
 impl CreateFlowLogsError {
     pub fn from_body(body: &str) -> CreateFlowLogsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57015,7 +57015,7 @@ pub enum CreateFpgaImageError {
 impl CreateFpgaImageError {
     pub fn from_body(body: &str) -> CreateFpgaImageError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57087,7 +57087,7 @@ pub enum CreateImageError {
 impl CreateImageError {
     pub fn from_body(body: &str) -> CreateImageError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57159,7 +57159,7 @@ pub enum CreateInstanceExportTaskError {
 impl CreateInstanceExportTaskError {
     pub fn from_body(body: &str) -> CreateInstanceExportTaskError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57233,7 +57233,7 @@ pub enum CreateInternetGatewayError {
 impl CreateInternetGatewayError {
     pub fn from_body(body: &str) -> CreateInternetGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57307,7 +57307,7 @@ pub enum CreateKeyPairError {
 impl CreateKeyPairError {
     pub fn from_body(body: &str) -> CreateKeyPairError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57379,7 +57379,7 @@ pub enum CreateLaunchTemplateError {
 impl CreateLaunchTemplateError {
     pub fn from_body(body: &str) -> CreateLaunchTemplateError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57453,7 +57453,7 @@ pub enum CreateLaunchTemplateVersionError {
 impl CreateLaunchTemplateVersionError {
     pub fn from_body(body: &str) -> CreateLaunchTemplateVersionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57527,7 +57527,7 @@ pub enum CreateNatGatewayError {
 impl CreateNatGatewayError {
     pub fn from_body(body: &str) -> CreateNatGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57599,7 +57599,7 @@ pub enum CreateNetworkAclError {
 impl CreateNetworkAclError {
     pub fn from_body(body: &str) -> CreateNetworkAclError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57671,7 +57671,7 @@ pub enum CreateNetworkAclEntryError {
 impl CreateNetworkAclEntryError {
     pub fn from_body(body: &str) -> CreateNetworkAclEntryError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57745,7 +57745,7 @@ pub enum CreateNetworkInterfaceError {
 impl CreateNetworkInterfaceError {
     pub fn from_body(body: &str) -> CreateNetworkInterfaceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57819,7 +57819,7 @@ pub enum CreateNetworkInterfacePermissionError {
 impl CreateNetworkInterfacePermissionError {
     pub fn from_body(body: &str) -> CreateNetworkInterfacePermissionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57893,7 +57893,7 @@ pub enum CreatePlacementGroupError {
 impl CreatePlacementGroupError {
     pub fn from_body(body: &str) -> CreatePlacementGroupError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -57967,7 +57967,7 @@ pub enum CreateReservedInstancesListingError {
 impl CreateReservedInstancesListingError {
     pub fn from_body(body: &str) -> CreateReservedInstancesListingError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58041,7 +58041,7 @@ pub enum CreateRouteError {
 impl CreateRouteError {
     pub fn from_body(body: &str) -> CreateRouteError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58113,7 +58113,7 @@ pub enum CreateRouteTableError {
 impl CreateRouteTableError {
     pub fn from_body(body: &str) -> CreateRouteTableError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58185,7 +58185,7 @@ pub enum CreateSecurityGroupError {
 impl CreateSecurityGroupError {
     pub fn from_body(body: &str) -> CreateSecurityGroupError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58259,7 +58259,7 @@ pub enum CreateSnapshotError {
 impl CreateSnapshotError {
     pub fn from_body(body: &str) -> CreateSnapshotError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58331,7 +58331,7 @@ pub enum CreateSpotDatafeedSubscriptionError {
 impl CreateSpotDatafeedSubscriptionError {
     pub fn from_body(body: &str) -> CreateSpotDatafeedSubscriptionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58405,7 +58405,7 @@ pub enum CreateSubnetError {
 impl CreateSubnetError {
     pub fn from_body(body: &str) -> CreateSubnetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58477,7 +58477,7 @@ pub enum CreateTagsError {
 impl CreateTagsError {
     pub fn from_body(body: &str) -> CreateTagsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58549,7 +58549,7 @@ pub enum CreateVolumeError {
 impl CreateVolumeError {
     pub fn from_body(body: &str) -> CreateVolumeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58621,7 +58621,7 @@ pub enum CreateVpcError {
 impl CreateVpcError {
     pub fn from_body(body: &str) -> CreateVpcError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58693,7 +58693,7 @@ pub enum CreateVpcEndpointError {
 impl CreateVpcEndpointError {
     pub fn from_body(body: &str) -> CreateVpcEndpointError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58767,7 +58767,7 @@ pub enum CreateVpcEndpointConnectionNotificationError {
 impl CreateVpcEndpointConnectionNotificationError {
     pub fn from_body(body: &str) -> CreateVpcEndpointConnectionNotificationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58841,7 +58841,7 @@ pub enum CreateVpcEndpointServiceConfigurationError {
 impl CreateVpcEndpointServiceConfigurationError {
     pub fn from_body(body: &str) -> CreateVpcEndpointServiceConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58915,7 +58915,7 @@ pub enum CreateVpcPeeringConnectionError {
 impl CreateVpcPeeringConnectionError {
     pub fn from_body(body: &str) -> CreateVpcPeeringConnectionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -58989,7 +58989,7 @@ pub enum CreateVpnConnectionError {
 impl CreateVpnConnectionError {
     pub fn from_body(body: &str) -> CreateVpnConnectionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59063,7 +59063,7 @@ pub enum CreateVpnConnectionRouteError {
 impl CreateVpnConnectionRouteError {
     pub fn from_body(body: &str) -> CreateVpnConnectionRouteError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59137,7 +59137,7 @@ pub enum CreateVpnGatewayError {
 impl CreateVpnGatewayError {
     pub fn from_body(body: &str) -> CreateVpnGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59209,7 +59209,7 @@ pub enum DeleteCustomerGatewayError {
 impl DeleteCustomerGatewayError {
     pub fn from_body(body: &str) -> DeleteCustomerGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59283,7 +59283,7 @@ pub enum DeleteDhcpOptionsError {
 impl DeleteDhcpOptionsError {
     pub fn from_body(body: &str) -> DeleteDhcpOptionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59357,7 +59357,7 @@ pub enum DeleteEgressOnlyInternetGatewayError {
 impl DeleteEgressOnlyInternetGatewayError {
     pub fn from_body(body: &str) -> DeleteEgressOnlyInternetGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59431,7 +59431,7 @@ pub enum DeleteFlowLogsError {
 impl DeleteFlowLogsError {
     pub fn from_body(body: &str) -> DeleteFlowLogsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59503,7 +59503,7 @@ pub enum DeleteFpgaImageError {
 impl DeleteFpgaImageError {
     pub fn from_body(body: &str) -> DeleteFpgaImageError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59575,7 +59575,7 @@ pub enum DeleteInternetGatewayError {
 impl DeleteInternetGatewayError {
     pub fn from_body(body: &str) -> DeleteInternetGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59649,7 +59649,7 @@ pub enum DeleteKeyPairError {
 impl DeleteKeyPairError {
     pub fn from_body(body: &str) -> DeleteKeyPairError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59721,7 +59721,7 @@ pub enum DeleteLaunchTemplateError {
 impl DeleteLaunchTemplateError {
     pub fn from_body(body: &str) -> DeleteLaunchTemplateError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59795,7 +59795,7 @@ pub enum DeleteLaunchTemplateVersionsError {
 impl DeleteLaunchTemplateVersionsError {
     pub fn from_body(body: &str) -> DeleteLaunchTemplateVersionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59869,7 +59869,7 @@ pub enum DeleteNatGatewayError {
 impl DeleteNatGatewayError {
     pub fn from_body(body: &str) -> DeleteNatGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -59941,7 +59941,7 @@ pub enum DeleteNetworkAclError {
 impl DeleteNetworkAclError {
     pub fn from_body(body: &str) -> DeleteNetworkAclError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60013,7 +60013,7 @@ pub enum DeleteNetworkAclEntryError {
 impl DeleteNetworkAclEntryError {
     pub fn from_body(body: &str) -> DeleteNetworkAclEntryError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60087,7 +60087,7 @@ pub enum DeleteNetworkInterfaceError {
 impl DeleteNetworkInterfaceError {
     pub fn from_body(body: &str) -> DeleteNetworkInterfaceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60161,7 +60161,7 @@ pub enum DeleteNetworkInterfacePermissionError {
 impl DeleteNetworkInterfacePermissionError {
     pub fn from_body(body: &str) -> DeleteNetworkInterfacePermissionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60235,7 +60235,7 @@ pub enum DeletePlacementGroupError {
 impl DeletePlacementGroupError {
     pub fn from_body(body: &str) -> DeletePlacementGroupError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60309,7 +60309,7 @@ pub enum DeleteRouteError {
 impl DeleteRouteError {
     pub fn from_body(body: &str) -> DeleteRouteError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60381,7 +60381,7 @@ pub enum DeleteRouteTableError {
 impl DeleteRouteTableError {
     pub fn from_body(body: &str) -> DeleteRouteTableError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60453,7 +60453,7 @@ pub enum DeleteSecurityGroupError {
 impl DeleteSecurityGroupError {
     pub fn from_body(body: &str) -> DeleteSecurityGroupError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60527,7 +60527,7 @@ pub enum DeleteSnapshotError {
 impl DeleteSnapshotError {
     pub fn from_body(body: &str) -> DeleteSnapshotError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60599,7 +60599,7 @@ pub enum DeleteSpotDatafeedSubscriptionError {
 impl DeleteSpotDatafeedSubscriptionError {
     pub fn from_body(body: &str) -> DeleteSpotDatafeedSubscriptionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60673,7 +60673,7 @@ pub enum DeleteSubnetError {
 impl DeleteSubnetError {
     pub fn from_body(body: &str) -> DeleteSubnetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60745,7 +60745,7 @@ pub enum DeleteTagsError {
 impl DeleteTagsError {
     pub fn from_body(body: &str) -> DeleteTagsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60817,7 +60817,7 @@ pub enum DeleteVolumeError {
 impl DeleteVolumeError {
     pub fn from_body(body: &str) -> DeleteVolumeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60889,7 +60889,7 @@ pub enum DeleteVpcError {
 impl DeleteVpcError {
     pub fn from_body(body: &str) -> DeleteVpcError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -60961,7 +60961,7 @@ pub enum DeleteVpcEndpointConnectionNotificationsError {
 impl DeleteVpcEndpointConnectionNotificationsError {
     pub fn from_body(body: &str) -> DeleteVpcEndpointConnectionNotificationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61037,7 +61037,7 @@ pub enum DeleteVpcEndpointServiceConfigurationsError {
 impl DeleteVpcEndpointServiceConfigurationsError {
     pub fn from_body(body: &str) -> DeleteVpcEndpointServiceConfigurationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61111,7 +61111,7 @@ pub enum DeleteVpcEndpointsError {
 impl DeleteVpcEndpointsError {
     pub fn from_body(body: &str) -> DeleteVpcEndpointsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61185,7 +61185,7 @@ pub enum DeleteVpcPeeringConnectionError {
 impl DeleteVpcPeeringConnectionError {
     pub fn from_body(body: &str) -> DeleteVpcPeeringConnectionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61259,7 +61259,7 @@ pub enum DeleteVpnConnectionError {
 impl DeleteVpnConnectionError {
     pub fn from_body(body: &str) -> DeleteVpnConnectionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61333,7 +61333,7 @@ pub enum DeleteVpnConnectionRouteError {
 impl DeleteVpnConnectionRouteError {
     pub fn from_body(body: &str) -> DeleteVpnConnectionRouteError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61407,7 +61407,7 @@ pub enum DeleteVpnGatewayError {
 impl DeleteVpnGatewayError {
     pub fn from_body(body: &str) -> DeleteVpnGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61479,7 +61479,7 @@ pub enum DeregisterImageError {
 impl DeregisterImageError {
     pub fn from_body(body: &str) -> DeregisterImageError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61551,7 +61551,7 @@ pub enum DescribeAccountAttributesError {
 impl DescribeAccountAttributesError {
     pub fn from_body(body: &str) -> DescribeAccountAttributesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61625,7 +61625,7 @@ pub enum DescribeAddressesError {
 impl DescribeAddressesError {
     pub fn from_body(body: &str) -> DescribeAddressesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61699,7 +61699,7 @@ pub enum DescribeAvailabilityZonesError {
 impl DescribeAvailabilityZonesError {
     pub fn from_body(body: &str) -> DescribeAvailabilityZonesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61773,7 +61773,7 @@ pub enum DescribeBundleTasksError {
 impl DescribeBundleTasksError {
     pub fn from_body(body: &str) -> DescribeBundleTasksError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61847,7 +61847,7 @@ pub enum DescribeClassicLinkInstancesError {
 impl DescribeClassicLinkInstancesError {
     pub fn from_body(body: &str) -> DescribeClassicLinkInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61921,7 +61921,7 @@ pub enum DescribeConversionTasksError {
 impl DescribeConversionTasksError {
     pub fn from_body(body: &str) -> DescribeConversionTasksError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -61995,7 +61995,7 @@ pub enum DescribeCustomerGatewaysError {
 impl DescribeCustomerGatewaysError {
     pub fn from_body(body: &str) -> DescribeCustomerGatewaysError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62069,7 +62069,7 @@ pub enum DescribeDhcpOptionsError {
 impl DescribeDhcpOptionsError {
     pub fn from_body(body: &str) -> DescribeDhcpOptionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62143,7 +62143,7 @@ pub enum DescribeEgressOnlyInternetGatewaysError {
 impl DescribeEgressOnlyInternetGatewaysError {
     pub fn from_body(body: &str) -> DescribeEgressOnlyInternetGatewaysError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62217,7 +62217,7 @@ pub enum DescribeElasticGpusError {
 impl DescribeElasticGpusError {
     pub fn from_body(body: &str) -> DescribeElasticGpusError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62291,7 +62291,7 @@ pub enum DescribeExportTasksError {
 impl DescribeExportTasksError {
     pub fn from_body(body: &str) -> DescribeExportTasksError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62365,7 +62365,7 @@ pub enum DescribeFlowLogsError {
 impl DescribeFlowLogsError {
     pub fn from_body(body: &str) -> DescribeFlowLogsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62437,7 +62437,7 @@ pub enum DescribeFpgaImageAttributeError {
 impl DescribeFpgaImageAttributeError {
     pub fn from_body(body: &str) -> DescribeFpgaImageAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62511,7 +62511,7 @@ pub enum DescribeFpgaImagesError {
 impl DescribeFpgaImagesError {
     pub fn from_body(body: &str) -> DescribeFpgaImagesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62585,7 +62585,7 @@ pub enum DescribeHostReservationOfferingsError {
 impl DescribeHostReservationOfferingsError {
     pub fn from_body(body: &str) -> DescribeHostReservationOfferingsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62659,7 +62659,7 @@ pub enum DescribeHostReservationsError {
 impl DescribeHostReservationsError {
     pub fn from_body(body: &str) -> DescribeHostReservationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62733,7 +62733,7 @@ pub enum DescribeHostsError {
 impl DescribeHostsError {
     pub fn from_body(body: &str) -> DescribeHostsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62805,7 +62805,7 @@ pub enum DescribeIamInstanceProfileAssociationsError {
 impl DescribeIamInstanceProfileAssociationsError {
     pub fn from_body(body: &str) -> DescribeIamInstanceProfileAssociationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62879,7 +62879,7 @@ pub enum DescribeIdFormatError {
 impl DescribeIdFormatError {
     pub fn from_body(body: &str) -> DescribeIdFormatError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -62951,7 +62951,7 @@ pub enum DescribeIdentityIdFormatError {
 impl DescribeIdentityIdFormatError {
     pub fn from_body(body: &str) -> DescribeIdentityIdFormatError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63025,7 +63025,7 @@ pub enum DescribeImageAttributeError {
 impl DescribeImageAttributeError {
     pub fn from_body(body: &str) -> DescribeImageAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63099,7 +63099,7 @@ pub enum DescribeImagesError {
 impl DescribeImagesError {
     pub fn from_body(body: &str) -> DescribeImagesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63171,7 +63171,7 @@ pub enum DescribeImportImageTasksError {
 impl DescribeImportImageTasksError {
     pub fn from_body(body: &str) -> DescribeImportImageTasksError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63245,7 +63245,7 @@ pub enum DescribeImportSnapshotTasksError {
 impl DescribeImportSnapshotTasksError {
     pub fn from_body(body: &str) -> DescribeImportSnapshotTasksError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63319,7 +63319,7 @@ pub enum DescribeInstanceAttributeError {
 impl DescribeInstanceAttributeError {
     pub fn from_body(body: &str) -> DescribeInstanceAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63393,7 +63393,7 @@ pub enum DescribeInstanceCreditSpecificationsError {
 impl DescribeInstanceCreditSpecificationsError {
     pub fn from_body(body: &str) -> DescribeInstanceCreditSpecificationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63467,7 +63467,7 @@ pub enum DescribeInstanceStatusError {
 impl DescribeInstanceStatusError {
     pub fn from_body(body: &str) -> DescribeInstanceStatusError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63541,7 +63541,7 @@ pub enum DescribeInstancesError {
 impl DescribeInstancesError {
     pub fn from_body(body: &str) -> DescribeInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63615,7 +63615,7 @@ pub enum DescribeInternetGatewaysError {
 impl DescribeInternetGatewaysError {
     pub fn from_body(body: &str) -> DescribeInternetGatewaysError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63689,7 +63689,7 @@ pub enum DescribeKeyPairsError {
 impl DescribeKeyPairsError {
     pub fn from_body(body: &str) -> DescribeKeyPairsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63761,7 +63761,7 @@ pub enum DescribeLaunchTemplateVersionsError {
 impl DescribeLaunchTemplateVersionsError {
     pub fn from_body(body: &str) -> DescribeLaunchTemplateVersionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63835,7 +63835,7 @@ pub enum DescribeLaunchTemplatesError {
 impl DescribeLaunchTemplatesError {
     pub fn from_body(body: &str) -> DescribeLaunchTemplatesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63909,7 +63909,7 @@ pub enum DescribeMovingAddressesError {
 impl DescribeMovingAddressesError {
     pub fn from_body(body: &str) -> DescribeMovingAddressesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -63983,7 +63983,7 @@ pub enum DescribeNatGatewaysError {
 impl DescribeNatGatewaysError {
     pub fn from_body(body: &str) -> DescribeNatGatewaysError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64057,7 +64057,7 @@ pub enum DescribeNetworkAclsError {
 impl DescribeNetworkAclsError {
     pub fn from_body(body: &str) -> DescribeNetworkAclsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64131,7 +64131,7 @@ pub enum DescribeNetworkInterfaceAttributeError {
 impl DescribeNetworkInterfaceAttributeError {
     pub fn from_body(body: &str) -> DescribeNetworkInterfaceAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64205,7 +64205,7 @@ pub enum DescribeNetworkInterfacePermissionsError {
 impl DescribeNetworkInterfacePermissionsError {
     pub fn from_body(body: &str) -> DescribeNetworkInterfacePermissionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64279,7 +64279,7 @@ pub enum DescribeNetworkInterfacesError {
 impl DescribeNetworkInterfacesError {
     pub fn from_body(body: &str) -> DescribeNetworkInterfacesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64353,7 +64353,7 @@ pub enum DescribePlacementGroupsError {
 impl DescribePlacementGroupsError {
     pub fn from_body(body: &str) -> DescribePlacementGroupsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64427,7 +64427,7 @@ pub enum DescribePrefixListsError {
 impl DescribePrefixListsError {
     pub fn from_body(body: &str) -> DescribePrefixListsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64501,7 +64501,7 @@ pub enum DescribeRegionsError {
 impl DescribeRegionsError {
     pub fn from_body(body: &str) -> DescribeRegionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64573,7 +64573,7 @@ pub enum DescribeReservedInstancesError {
 impl DescribeReservedInstancesError {
     pub fn from_body(body: &str) -> DescribeReservedInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64647,7 +64647,7 @@ pub enum DescribeReservedInstancesListingsError {
 impl DescribeReservedInstancesListingsError {
     pub fn from_body(body: &str) -> DescribeReservedInstancesListingsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64721,7 +64721,7 @@ pub enum DescribeReservedInstancesModificationsError {
 impl DescribeReservedInstancesModificationsError {
     pub fn from_body(body: &str) -> DescribeReservedInstancesModificationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64795,7 +64795,7 @@ pub enum DescribeReservedInstancesOfferingsError {
 impl DescribeReservedInstancesOfferingsError {
     pub fn from_body(body: &str) -> DescribeReservedInstancesOfferingsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64869,7 +64869,7 @@ pub enum DescribeRouteTablesError {
 impl DescribeRouteTablesError {
     pub fn from_body(body: &str) -> DescribeRouteTablesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -64943,7 +64943,7 @@ pub enum DescribeScheduledInstanceAvailabilityError {
 impl DescribeScheduledInstanceAvailabilityError {
     pub fn from_body(body: &str) -> DescribeScheduledInstanceAvailabilityError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65017,7 +65017,7 @@ pub enum DescribeScheduledInstancesError {
 impl DescribeScheduledInstancesError {
     pub fn from_body(body: &str) -> DescribeScheduledInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65091,7 +65091,7 @@ pub enum DescribeSecurityGroupReferencesError {
 impl DescribeSecurityGroupReferencesError {
     pub fn from_body(body: &str) -> DescribeSecurityGroupReferencesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65165,7 +65165,7 @@ pub enum DescribeSecurityGroupsError {
 impl DescribeSecurityGroupsError {
     pub fn from_body(body: &str) -> DescribeSecurityGroupsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65239,7 +65239,7 @@ pub enum DescribeSnapshotAttributeError {
 impl DescribeSnapshotAttributeError {
     pub fn from_body(body: &str) -> DescribeSnapshotAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65313,7 +65313,7 @@ pub enum DescribeSnapshotsError {
 impl DescribeSnapshotsError {
     pub fn from_body(body: &str) -> DescribeSnapshotsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65387,7 +65387,7 @@ pub enum DescribeSpotDatafeedSubscriptionError {
 impl DescribeSpotDatafeedSubscriptionError {
     pub fn from_body(body: &str) -> DescribeSpotDatafeedSubscriptionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65461,7 +65461,7 @@ pub enum DescribeSpotFleetInstancesError {
 impl DescribeSpotFleetInstancesError {
     pub fn from_body(body: &str) -> DescribeSpotFleetInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65535,7 +65535,7 @@ pub enum DescribeSpotFleetRequestHistoryError {
 impl DescribeSpotFleetRequestHistoryError {
     pub fn from_body(body: &str) -> DescribeSpotFleetRequestHistoryError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65609,7 +65609,7 @@ pub enum DescribeSpotFleetRequestsError {
 impl DescribeSpotFleetRequestsError {
     pub fn from_body(body: &str) -> DescribeSpotFleetRequestsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65683,7 +65683,7 @@ pub enum DescribeSpotInstanceRequestsError {
 impl DescribeSpotInstanceRequestsError {
     pub fn from_body(body: &str) -> DescribeSpotInstanceRequestsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65757,7 +65757,7 @@ pub enum DescribeSpotPriceHistoryError {
 impl DescribeSpotPriceHistoryError {
     pub fn from_body(body: &str) -> DescribeSpotPriceHistoryError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65831,7 +65831,7 @@ pub enum DescribeStaleSecurityGroupsError {
 impl DescribeStaleSecurityGroupsError {
     pub fn from_body(body: &str) -> DescribeStaleSecurityGroupsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65905,7 +65905,7 @@ pub enum DescribeSubnetsError {
 impl DescribeSubnetsError {
     pub fn from_body(body: &str) -> DescribeSubnetsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -65977,7 +65977,7 @@ pub enum DescribeTagsError {
 impl DescribeTagsError {
     pub fn from_body(body: &str) -> DescribeTagsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66049,7 +66049,7 @@ pub enum DescribeVolumeAttributeError {
 impl DescribeVolumeAttributeError {
     pub fn from_body(body: &str) -> DescribeVolumeAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66123,7 +66123,7 @@ pub enum DescribeVolumeStatusError {
 impl DescribeVolumeStatusError {
     pub fn from_body(body: &str) -> DescribeVolumeStatusError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66197,7 +66197,7 @@ pub enum DescribeVolumesError {
 impl DescribeVolumesError {
     pub fn from_body(body: &str) -> DescribeVolumesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66269,7 +66269,7 @@ pub enum DescribeVolumesModificationsError {
 impl DescribeVolumesModificationsError {
     pub fn from_body(body: &str) -> DescribeVolumesModificationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66343,7 +66343,7 @@ pub enum DescribeVpcAttributeError {
 impl DescribeVpcAttributeError {
     pub fn from_body(body: &str) -> DescribeVpcAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66417,7 +66417,7 @@ pub enum DescribeVpcClassicLinkError {
 impl DescribeVpcClassicLinkError {
     pub fn from_body(body: &str) -> DescribeVpcClassicLinkError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66491,7 +66491,7 @@ pub enum DescribeVpcClassicLinkDnsSupportError {
 impl DescribeVpcClassicLinkDnsSupportError {
     pub fn from_body(body: &str) -> DescribeVpcClassicLinkDnsSupportError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66565,7 +66565,7 @@ pub enum DescribeVpcEndpointConnectionNotificationsError {
 impl DescribeVpcEndpointConnectionNotificationsError {
     pub fn from_body(body: &str) -> DescribeVpcEndpointConnectionNotificationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66641,7 +66641,7 @@ pub enum DescribeVpcEndpointConnectionsError {
 impl DescribeVpcEndpointConnectionsError {
     pub fn from_body(body: &str) -> DescribeVpcEndpointConnectionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66715,7 +66715,7 @@ pub enum DescribeVpcEndpointServiceConfigurationsError {
 impl DescribeVpcEndpointServiceConfigurationsError {
     pub fn from_body(body: &str) -> DescribeVpcEndpointServiceConfigurationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66791,7 +66791,7 @@ pub enum DescribeVpcEndpointServicePermissionsError {
 impl DescribeVpcEndpointServicePermissionsError {
     pub fn from_body(body: &str) -> DescribeVpcEndpointServicePermissionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66865,7 +66865,7 @@ pub enum DescribeVpcEndpointServicesError {
 impl DescribeVpcEndpointServicesError {
     pub fn from_body(body: &str) -> DescribeVpcEndpointServicesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -66939,7 +66939,7 @@ pub enum DescribeVpcEndpointsError {
 impl DescribeVpcEndpointsError {
     pub fn from_body(body: &str) -> DescribeVpcEndpointsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67013,7 +67013,7 @@ pub enum DescribeVpcPeeringConnectionsError {
 impl DescribeVpcPeeringConnectionsError {
     pub fn from_body(body: &str) -> DescribeVpcPeeringConnectionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67087,7 +67087,7 @@ pub enum DescribeVpcsError {
 impl DescribeVpcsError {
     pub fn from_body(body: &str) -> DescribeVpcsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67159,7 +67159,7 @@ pub enum DescribeVpnConnectionsError {
 impl DescribeVpnConnectionsError {
     pub fn from_body(body: &str) -> DescribeVpnConnectionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67233,7 +67233,7 @@ pub enum DescribeVpnGatewaysError {
 impl DescribeVpnGatewaysError {
     pub fn from_body(body: &str) -> DescribeVpnGatewaysError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67307,7 +67307,7 @@ pub enum DetachClassicLinkVpcError {
 impl DetachClassicLinkVpcError {
     pub fn from_body(body: &str) -> DetachClassicLinkVpcError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67381,7 +67381,7 @@ pub enum DetachInternetGatewayError {
 impl DetachInternetGatewayError {
     pub fn from_body(body: &str) -> DetachInternetGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67455,7 +67455,7 @@ pub enum DetachNetworkInterfaceError {
 impl DetachNetworkInterfaceError {
     pub fn from_body(body: &str) -> DetachNetworkInterfaceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67529,7 +67529,7 @@ pub enum DetachVolumeError {
 impl DetachVolumeError {
     pub fn from_body(body: &str) -> DetachVolumeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67601,7 +67601,7 @@ pub enum DetachVpnGatewayError {
 impl DetachVpnGatewayError {
     pub fn from_body(body: &str) -> DetachVpnGatewayError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67673,7 +67673,7 @@ pub enum DisableVgwRoutePropagationError {
 impl DisableVgwRoutePropagationError {
     pub fn from_body(body: &str) -> DisableVgwRoutePropagationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67747,7 +67747,7 @@ pub enum DisableVpcClassicLinkError {
 impl DisableVpcClassicLinkError {
     pub fn from_body(body: &str) -> DisableVpcClassicLinkError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67821,7 +67821,7 @@ pub enum DisableVpcClassicLinkDnsSupportError {
 impl DisableVpcClassicLinkDnsSupportError {
     pub fn from_body(body: &str) -> DisableVpcClassicLinkDnsSupportError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67895,7 +67895,7 @@ pub enum DisassociateAddressError {
 impl DisassociateAddressError {
     pub fn from_body(body: &str) -> DisassociateAddressError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -67969,7 +67969,7 @@ pub enum DisassociateIamInstanceProfileError {
 impl DisassociateIamInstanceProfileError {
     pub fn from_body(body: &str) -> DisassociateIamInstanceProfileError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68043,7 +68043,7 @@ pub enum DisassociateRouteTableError {
 impl DisassociateRouteTableError {
     pub fn from_body(body: &str) -> DisassociateRouteTableError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68117,7 +68117,7 @@ pub enum DisassociateSubnetCidrBlockError {
 impl DisassociateSubnetCidrBlockError {
     pub fn from_body(body: &str) -> DisassociateSubnetCidrBlockError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68191,7 +68191,7 @@ pub enum DisassociateVpcCidrBlockError {
 impl DisassociateVpcCidrBlockError {
     pub fn from_body(body: &str) -> DisassociateVpcCidrBlockError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68265,7 +68265,7 @@ pub enum EnableVgwRoutePropagationError {
 impl EnableVgwRoutePropagationError {
     pub fn from_body(body: &str) -> EnableVgwRoutePropagationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68339,7 +68339,7 @@ pub enum EnableVolumeIOError {
 impl EnableVolumeIOError {
     pub fn from_body(body: &str) -> EnableVolumeIOError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68411,7 +68411,7 @@ pub enum EnableVpcClassicLinkError {
 impl EnableVpcClassicLinkError {
     pub fn from_body(body: &str) -> EnableVpcClassicLinkError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68485,7 +68485,7 @@ pub enum EnableVpcClassicLinkDnsSupportError {
 impl EnableVpcClassicLinkDnsSupportError {
     pub fn from_body(body: &str) -> EnableVpcClassicLinkDnsSupportError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68559,7 +68559,7 @@ pub enum GetConsoleOutputError {
 impl GetConsoleOutputError {
     pub fn from_body(body: &str) -> GetConsoleOutputError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68631,7 +68631,7 @@ pub enum GetConsoleScreenshotError {
 impl GetConsoleScreenshotError {
     pub fn from_body(body: &str) -> GetConsoleScreenshotError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68705,7 +68705,7 @@ pub enum GetHostReservationPurchasePreviewError {
 impl GetHostReservationPurchasePreviewError {
     pub fn from_body(body: &str) -> GetHostReservationPurchasePreviewError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68779,7 +68779,7 @@ pub enum GetLaunchTemplateDataError {
 impl GetLaunchTemplateDataError {
     pub fn from_body(body: &str) -> GetLaunchTemplateDataError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68853,7 +68853,7 @@ pub enum GetPasswordDataError {
 impl GetPasswordDataError {
     pub fn from_body(body: &str) -> GetPasswordDataError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68925,7 +68925,7 @@ pub enum GetReservedInstancesExchangeQuoteError {
 impl GetReservedInstancesExchangeQuoteError {
     pub fn from_body(body: &str) -> GetReservedInstancesExchangeQuoteError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -68999,7 +68999,7 @@ pub enum ImportImageError {
 impl ImportImageError {
     pub fn from_body(body: &str) -> ImportImageError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69071,7 +69071,7 @@ pub enum ImportInstanceError {
 impl ImportInstanceError {
     pub fn from_body(body: &str) -> ImportInstanceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69143,7 +69143,7 @@ pub enum ImportKeyPairError {
 impl ImportKeyPairError {
     pub fn from_body(body: &str) -> ImportKeyPairError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69215,7 +69215,7 @@ pub enum ImportSnapshotError {
 impl ImportSnapshotError {
     pub fn from_body(body: &str) -> ImportSnapshotError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69287,7 +69287,7 @@ pub enum ImportVolumeError {
 impl ImportVolumeError {
     pub fn from_body(body: &str) -> ImportVolumeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69359,7 +69359,7 @@ pub enum ModifyFpgaImageAttributeError {
 impl ModifyFpgaImageAttributeError {
     pub fn from_body(body: &str) -> ModifyFpgaImageAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69433,7 +69433,7 @@ pub enum ModifyHostsError {
 impl ModifyHostsError {
     pub fn from_body(body: &str) -> ModifyHostsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69505,7 +69505,7 @@ pub enum ModifyIdFormatError {
 impl ModifyIdFormatError {
     pub fn from_body(body: &str) -> ModifyIdFormatError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69577,7 +69577,7 @@ pub enum ModifyIdentityIdFormatError {
 impl ModifyIdentityIdFormatError {
     pub fn from_body(body: &str) -> ModifyIdentityIdFormatError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69651,7 +69651,7 @@ pub enum ModifyImageAttributeError {
 impl ModifyImageAttributeError {
     pub fn from_body(body: &str) -> ModifyImageAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69725,7 +69725,7 @@ pub enum ModifyInstanceAttributeError {
 impl ModifyInstanceAttributeError {
     pub fn from_body(body: &str) -> ModifyInstanceAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69799,7 +69799,7 @@ pub enum ModifyInstanceCreditSpecificationError {
 impl ModifyInstanceCreditSpecificationError {
     pub fn from_body(body: &str) -> ModifyInstanceCreditSpecificationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69873,7 +69873,7 @@ pub enum ModifyInstancePlacementError {
 impl ModifyInstancePlacementError {
     pub fn from_body(body: &str) -> ModifyInstancePlacementError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -69947,7 +69947,7 @@ pub enum ModifyLaunchTemplateError {
 impl ModifyLaunchTemplateError {
     pub fn from_body(body: &str) -> ModifyLaunchTemplateError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70021,7 +70021,7 @@ pub enum ModifyNetworkInterfaceAttributeError {
 impl ModifyNetworkInterfaceAttributeError {
     pub fn from_body(body: &str) -> ModifyNetworkInterfaceAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70095,7 +70095,7 @@ pub enum ModifyReservedInstancesError {
 impl ModifyReservedInstancesError {
     pub fn from_body(body: &str) -> ModifyReservedInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70169,7 +70169,7 @@ pub enum ModifySnapshotAttributeError {
 impl ModifySnapshotAttributeError {
     pub fn from_body(body: &str) -> ModifySnapshotAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70243,7 +70243,7 @@ pub enum ModifySpotFleetRequestError {
 impl ModifySpotFleetRequestError {
     pub fn from_body(body: &str) -> ModifySpotFleetRequestError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70317,7 +70317,7 @@ pub enum ModifySubnetAttributeError {
 impl ModifySubnetAttributeError {
     pub fn from_body(body: &str) -> ModifySubnetAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70391,7 +70391,7 @@ pub enum ModifyVolumeError {
 impl ModifyVolumeError {
     pub fn from_body(body: &str) -> ModifyVolumeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70463,7 +70463,7 @@ pub enum ModifyVolumeAttributeError {
 impl ModifyVolumeAttributeError {
     pub fn from_body(body: &str) -> ModifyVolumeAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70537,7 +70537,7 @@ pub enum ModifyVpcAttributeError {
 impl ModifyVpcAttributeError {
     pub fn from_body(body: &str) -> ModifyVpcAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70611,7 +70611,7 @@ pub enum ModifyVpcEndpointError {
 impl ModifyVpcEndpointError {
     pub fn from_body(body: &str) -> ModifyVpcEndpointError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70685,7 +70685,7 @@ pub enum ModifyVpcEndpointConnectionNotificationError {
 impl ModifyVpcEndpointConnectionNotificationError {
     pub fn from_body(body: &str) -> ModifyVpcEndpointConnectionNotificationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70759,7 +70759,7 @@ pub enum ModifyVpcEndpointServiceConfigurationError {
 impl ModifyVpcEndpointServiceConfigurationError {
     pub fn from_body(body: &str) -> ModifyVpcEndpointServiceConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70833,7 +70833,7 @@ pub enum ModifyVpcEndpointServicePermissionsError {
 impl ModifyVpcEndpointServicePermissionsError {
     pub fn from_body(body: &str) -> ModifyVpcEndpointServicePermissionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70907,7 +70907,7 @@ pub enum ModifyVpcPeeringConnectionOptionsError {
 impl ModifyVpcPeeringConnectionOptionsError {
     pub fn from_body(body: &str) -> ModifyVpcPeeringConnectionOptionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -70981,7 +70981,7 @@ pub enum ModifyVpcTenancyError {
 impl ModifyVpcTenancyError {
     pub fn from_body(body: &str) -> ModifyVpcTenancyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71053,7 +71053,7 @@ pub enum MonitorInstancesError {
 impl MonitorInstancesError {
     pub fn from_body(body: &str) -> MonitorInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71125,7 +71125,7 @@ pub enum MoveAddressToVpcError {
 impl MoveAddressToVpcError {
     pub fn from_body(body: &str) -> MoveAddressToVpcError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71197,7 +71197,7 @@ pub enum PurchaseHostReservationError {
 impl PurchaseHostReservationError {
     pub fn from_body(body: &str) -> PurchaseHostReservationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71271,7 +71271,7 @@ pub enum PurchaseReservedInstancesOfferingError {
 impl PurchaseReservedInstancesOfferingError {
     pub fn from_body(body: &str) -> PurchaseReservedInstancesOfferingError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71345,7 +71345,7 @@ pub enum PurchaseScheduledInstancesError {
 impl PurchaseScheduledInstancesError {
     pub fn from_body(body: &str) -> PurchaseScheduledInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71419,7 +71419,7 @@ pub enum RebootInstancesError {
 impl RebootInstancesError {
     pub fn from_body(body: &str) -> RebootInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71491,7 +71491,7 @@ pub enum RegisterImageError {
 impl RegisterImageError {
     pub fn from_body(body: &str) -> RegisterImageError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71563,7 +71563,7 @@ pub enum RejectVpcEndpointConnectionsError {
 impl RejectVpcEndpointConnectionsError {
     pub fn from_body(body: &str) -> RejectVpcEndpointConnectionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71637,7 +71637,7 @@ pub enum RejectVpcPeeringConnectionError {
 impl RejectVpcPeeringConnectionError {
     pub fn from_body(body: &str) -> RejectVpcPeeringConnectionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71711,7 +71711,7 @@ pub enum ReleaseAddressError {
 impl ReleaseAddressError {
     pub fn from_body(body: &str) -> ReleaseAddressError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71783,7 +71783,7 @@ pub enum ReleaseHostsError {
 impl ReleaseHostsError {
     pub fn from_body(body: &str) -> ReleaseHostsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71855,7 +71855,7 @@ pub enum ReplaceIamInstanceProfileAssociationError {
 impl ReplaceIamInstanceProfileAssociationError {
     pub fn from_body(body: &str) -> ReplaceIamInstanceProfileAssociationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -71929,7 +71929,7 @@ pub enum ReplaceNetworkAclAssociationError {
 impl ReplaceNetworkAclAssociationError {
     pub fn from_body(body: &str) -> ReplaceNetworkAclAssociationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72003,7 +72003,7 @@ pub enum ReplaceNetworkAclEntryError {
 impl ReplaceNetworkAclEntryError {
     pub fn from_body(body: &str) -> ReplaceNetworkAclEntryError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72077,7 +72077,7 @@ pub enum ReplaceRouteError {
 impl ReplaceRouteError {
     pub fn from_body(body: &str) -> ReplaceRouteError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72149,7 +72149,7 @@ pub enum ReplaceRouteTableAssociationError {
 impl ReplaceRouteTableAssociationError {
     pub fn from_body(body: &str) -> ReplaceRouteTableAssociationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72223,7 +72223,7 @@ pub enum ReportInstanceStatusError {
 impl ReportInstanceStatusError {
     pub fn from_body(body: &str) -> ReportInstanceStatusError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72297,7 +72297,7 @@ pub enum RequestSpotFleetError {
 impl RequestSpotFleetError {
     pub fn from_body(body: &str) -> RequestSpotFleetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72369,7 +72369,7 @@ pub enum RequestSpotInstancesError {
 impl RequestSpotInstancesError {
     pub fn from_body(body: &str) -> RequestSpotInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72443,7 +72443,7 @@ pub enum ResetFpgaImageAttributeError {
 impl ResetFpgaImageAttributeError {
     pub fn from_body(body: &str) -> ResetFpgaImageAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72517,7 +72517,7 @@ pub enum ResetImageAttributeError {
 impl ResetImageAttributeError {
     pub fn from_body(body: &str) -> ResetImageAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72591,7 +72591,7 @@ pub enum ResetInstanceAttributeError {
 impl ResetInstanceAttributeError {
     pub fn from_body(body: &str) -> ResetInstanceAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72665,7 +72665,7 @@ pub enum ResetNetworkInterfaceAttributeError {
 impl ResetNetworkInterfaceAttributeError {
     pub fn from_body(body: &str) -> ResetNetworkInterfaceAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72739,7 +72739,7 @@ pub enum ResetSnapshotAttributeError {
 impl ResetSnapshotAttributeError {
     pub fn from_body(body: &str) -> ResetSnapshotAttributeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72813,7 +72813,7 @@ pub enum RestoreAddressToClassicError {
 impl RestoreAddressToClassicError {
     pub fn from_body(body: &str) -> RestoreAddressToClassicError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72887,7 +72887,7 @@ pub enum RevokeSecurityGroupEgressError {
 impl RevokeSecurityGroupEgressError {
     pub fn from_body(body: &str) -> RevokeSecurityGroupEgressError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -72961,7 +72961,7 @@ pub enum RevokeSecurityGroupIngressError {
 impl RevokeSecurityGroupIngressError {
     pub fn from_body(body: &str) -> RevokeSecurityGroupIngressError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -73035,7 +73035,7 @@ pub enum RunInstancesError {
 impl RunInstancesError {
     pub fn from_body(body: &str) -> RunInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -73107,7 +73107,7 @@ pub enum RunScheduledInstancesError {
 impl RunScheduledInstancesError {
     pub fn from_body(body: &str) -> RunScheduledInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -73181,7 +73181,7 @@ pub enum StartInstancesError {
 impl StartInstancesError {
     pub fn from_body(body: &str) -> StartInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -73253,7 +73253,7 @@ pub enum StopInstancesError {
 impl StopInstancesError {
     pub fn from_body(body: &str) -> StopInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -73325,7 +73325,7 @@ pub enum TerminateInstancesError {
 impl TerminateInstancesError {
     pub fn from_body(body: &str) -> TerminateInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -73399,7 +73399,7 @@ pub enum UnassignIpv6AddressesError {
 impl UnassignIpv6AddressesError {
     pub fn from_body(body: &str) -> UnassignIpv6AddressesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -73473,7 +73473,7 @@ pub enum UnassignPrivateIpAddressesError {
 impl UnassignPrivateIpAddressesError {
     pub fn from_body(body: &str) -> UnassignPrivateIpAddressesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -73547,7 +73547,7 @@ pub enum UnmonitorInstancesError {
 impl UnmonitorInstancesError {
     pub fn from_body(body: &str) -> UnmonitorInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -73621,7 +73621,7 @@ pub enum UpdateSecurityGroupRuleDescriptionsEgressError {
 impl UpdateSecurityGroupRuleDescriptionsEgressError {
     pub fn from_body(body: &str) -> UpdateSecurityGroupRuleDescriptionsEgressError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -73697,7 +73697,7 @@ pub enum UpdateSecurityGroupRuleDescriptionsIngressError {
 impl UpdateSecurityGroupRuleDescriptionsIngressError {
     pub fn from_body(body: &str) -> UpdateSecurityGroupRuleDescriptionsIngressError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -75467,8 +75467,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         AcceptReservedInstancesExchangeQuoteResultDeserializer::deserialize(
@@ -75517,8 +75516,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         AcceptVpcEndpointConnectionsResultDeserializer::deserialize(
@@ -75567,8 +75565,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AcceptVpcPeeringConnectionResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -75615,8 +75612,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AllocateAddressResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -75663,8 +75659,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AllocateHostsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -75711,8 +75706,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AssignIpv6AddressesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -75787,8 +75781,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AssociateAddressResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -75863,8 +75856,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AssociateIamInstanceProfileResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -75911,8 +75903,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AssociateRouteTableResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -75959,8 +75950,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AssociateSubnetCidrBlockResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76007,8 +75997,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AssociateVpcCidrBlockResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76055,8 +76044,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AttachClassicLinkVpcResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76131,8 +76119,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AttachNetworkInterfaceResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76179,8 +76166,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(VolumeAttachmentDeserializer::deserialize(
                         &actual_tag_name,
@@ -76227,8 +76213,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AttachVpnGatewayResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76331,8 +76316,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(BundleInstanceResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76379,8 +76363,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CancelBundleTaskResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76483,8 +76466,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CancelImportTaskResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76532,8 +76514,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CancelReservedInstancesListingResultDeserializer::deserialize(
@@ -76582,8 +76563,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CancelSpotFleetRequestsResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -76630,8 +76610,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CancelSpotInstanceRequestsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76678,8 +76657,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ConfirmProductInstanceResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76726,8 +76704,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CopyFpgaImageResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76774,8 +76751,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CopyImageResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76822,8 +76798,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CopySnapshotResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76870,8 +76845,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateCustomerGatewayResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76918,8 +76892,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateDefaultSubnetResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -76966,8 +76939,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateDefaultVpcResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77014,8 +76986,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateDhcpOptionsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77063,8 +77034,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CreateEgressOnlyInternetGatewayResultDeserializer::deserialize(
@@ -77113,8 +77083,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateFlowLogsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77161,8 +77130,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateFpgaImageResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77209,8 +77177,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateImageResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77257,8 +77224,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateInstanceExportTaskResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77305,8 +77271,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateInternetGatewayResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77353,8 +77318,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(KeyPairDeserializer::deserialize(
                         &actual_tag_name,
@@ -77401,8 +77365,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateLaunchTemplateResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77449,8 +77412,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateLaunchTemplateVersionResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77497,8 +77459,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateNatGatewayResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77545,8 +77506,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateNetworkAclResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77621,8 +77581,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateNetworkInterfaceResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77670,8 +77629,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CreateNetworkInterfacePermissionResultDeserializer::deserialize(
@@ -77749,8 +77707,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CreateReservedInstancesListingResultDeserializer::deserialize(
@@ -77799,8 +77756,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateRouteResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77847,8 +77803,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateRouteTableResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77895,8 +77850,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateSecurityGroupResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -77943,8 +77897,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(SnapshotDeserializer::deserialize(
                         &actual_tag_name,
@@ -77992,8 +77945,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CreateSpotDatafeedSubscriptionResultDeserializer::deserialize(
@@ -78042,8 +77994,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateSubnetResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -78115,8 +78066,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(VolumeDeserializer::deserialize(
                         &actual_tag_name,
@@ -78163,8 +78113,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateVpcResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -78211,8 +78160,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateVpcEndpointResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -78266,8 +78214,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CreateVpcEndpointConnectionNotificationResultDeserializer::deserialize(
@@ -78319,8 +78266,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CreateVpcEndpointServiceConfigurationResultDeserializer::deserialize(
@@ -78369,8 +78315,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateVpcPeeringConnectionResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -78417,8 +78362,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateVpnConnectionResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -78493,8 +78437,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateVpnGatewayResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -78598,8 +78541,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DeleteEgressOnlyInternetGatewayResultDeserializer::deserialize(
@@ -78648,8 +78590,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteFlowLogsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -78696,8 +78637,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteFpgaImageResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -78800,8 +78740,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteLaunchTemplateResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -78848,8 +78787,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DeleteLaunchTemplateVersionsResultDeserializer::deserialize(
@@ -78898,8 +78836,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteNatGatewayResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -79031,8 +78968,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DeleteNetworkInterfacePermissionResultDeserializer::deserialize(
@@ -79353,8 +79289,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DeleteVpcEndpointConnectionNotificationsResultDeserializer::deserialize(
@@ -79406,8 +79341,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DeleteVpcEndpointServiceConfigurationsResultDeserializer::deserialize(
@@ -79456,8 +79390,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteVpcEndpointsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -79504,8 +79437,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteVpcPeeringConnectionResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -79664,8 +79596,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeAccountAttributesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -79712,8 +79643,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeAddressesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -79760,8 +79690,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeAvailabilityZonesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -79808,8 +79737,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeBundleTasksResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -79856,8 +79784,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeClassicLinkInstancesResultDeserializer::deserialize(
@@ -79906,8 +79833,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeConversionTasksResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -79954,8 +79880,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeCustomerGatewaysResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80002,8 +79927,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeDhcpOptionsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80053,8 +79977,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeEgressOnlyInternetGatewaysResultDeserializer::deserialize(
@@ -80103,8 +80026,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeElasticGpusResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80151,8 +80073,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeExportTasksResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80199,8 +80120,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeFlowLogsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80247,8 +80167,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeFpgaImageAttributeResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80295,8 +80214,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeFpgaImagesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80344,8 +80262,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeHostReservationOfferingsResultDeserializer::deserialize(
@@ -80394,8 +80311,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeHostReservationsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80442,8 +80358,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeHostsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80493,8 +80408,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeIamInstanceProfileAssociationsResultDeserializer::deserialize(
@@ -80543,8 +80457,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeIdFormatResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80591,8 +80504,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeIdentityIdFormatResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80639,8 +80551,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ImageAttributeDeserializer::deserialize(
                         &actual_tag_name,
@@ -80687,8 +80598,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeImagesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80735,8 +80645,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeImportImageTasksResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80783,8 +80692,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeImportSnapshotTasksResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80831,8 +80739,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(InstanceAttributeDeserializer::deserialize(
                         &actual_tag_name,
@@ -80882,8 +80789,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeInstanceCreditSpecificationsResultDeserializer::deserialize(
@@ -80932,8 +80838,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeInstanceStatusResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -80980,8 +80885,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeInstancesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81028,8 +80932,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeInternetGatewaysResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81076,8 +80979,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeKeyPairsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81125,8 +81027,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeLaunchTemplateVersionsResultDeserializer::deserialize(
@@ -81175,8 +81076,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeLaunchTemplatesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81223,8 +81123,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeMovingAddressesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81271,8 +81170,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeNatGatewaysResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81319,8 +81217,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeNetworkAclsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81368,8 +81265,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeNetworkInterfaceAttributeResultDeserializer::deserialize(
@@ -81421,8 +81317,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeNetworkInterfacePermissionsResultDeserializer::deserialize(
@@ -81471,8 +81366,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeNetworkInterfacesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81519,8 +81413,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribePlacementGroupsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81567,8 +81460,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribePrefixListsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81615,8 +81507,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeRegionsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81663,8 +81554,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeReservedInstancesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81712,8 +81602,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeReservedInstancesListingsResultDeserializer::deserialize(
@@ -81765,8 +81654,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeReservedInstancesModificationsResultDeserializer::deserialize(
@@ -81818,8 +81706,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeReservedInstancesOfferingsResultDeserializer::deserialize(
@@ -81868,8 +81755,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeRouteTablesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -81919,8 +81805,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeScheduledInstanceAvailabilityResultDeserializer::deserialize(
@@ -81969,8 +81854,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeScheduledInstancesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82018,8 +81902,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeSecurityGroupReferencesResultDeserializer::deserialize(
@@ -82068,8 +81951,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeSecurityGroupsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82116,8 +81998,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeSnapshotAttributeResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82164,8 +82045,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeSnapshotsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82213,8 +82093,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeSpotDatafeedSubscriptionResultDeserializer::deserialize(
@@ -82263,8 +82142,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeSpotFleetInstancesResponseDeserializer::deserialize(
@@ -82314,8 +82192,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeSpotFleetRequestHistoryResponseDeserializer::deserialize(
@@ -82364,8 +82241,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeSpotFleetRequestsResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -82412,8 +82288,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeSpotInstanceRequestsResultDeserializer::deserialize(
@@ -82462,8 +82337,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeSpotPriceHistoryResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82510,8 +82384,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeStaleSecurityGroupsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82558,8 +82431,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeSubnetsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82606,8 +82478,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeTagsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82654,8 +82525,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeVolumeAttributeResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82702,8 +82572,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeVolumeStatusResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82750,8 +82619,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeVolumesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82798,8 +82666,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeVolumesModificationsResultDeserializer::deserialize(
@@ -82848,8 +82715,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeVpcAttributeResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82896,8 +82762,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeVpcClassicLinkResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -82945,8 +82810,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeVpcClassicLinkDnsSupportResultDeserializer::deserialize(
@@ -83002,8 +82866,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeVpcEndpointConnectionNotificationsResultDeserializer::deserialize(
@@ -83053,8 +82916,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeVpcEndpointConnectionsResultDeserializer::deserialize(
@@ -83110,8 +82972,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeVpcEndpointServiceConfigurationsResultDeserializer::deserialize(
@@ -83163,8 +83024,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeVpcEndpointServicePermissionsResultDeserializer::deserialize(
@@ -83213,8 +83073,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeVpcEndpointServicesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -83261,8 +83120,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeVpcEndpointsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -83309,8 +83167,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DescribeVpcPeeringConnectionsResultDeserializer::deserialize(
@@ -83359,8 +83216,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeVpcsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -83407,8 +83263,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeVpnConnectionsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -83455,8 +83310,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DescribeVpnGatewaysResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -83503,8 +83357,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DetachClassicLinkVpcResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -83607,8 +83460,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(VolumeAttachmentDeserializer::deserialize(
                         &actual_tag_name,
@@ -83711,8 +83563,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DisableVpcClassicLinkResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -83760,8 +83611,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DisableVpcClassicLinkDnsSupportResultDeserializer::deserialize(
@@ -83839,8 +83689,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DisassociateIamInstanceProfileResultDeserializer::deserialize(
@@ -83917,8 +83766,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DisassociateSubnetCidrBlockResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -83965,8 +83813,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DisassociateVpcCidrBlockResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84069,8 +83916,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(EnableVpcClassicLinkResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84118,8 +83964,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         EnableVpcClassicLinkDnsSupportResultDeserializer::deserialize(
@@ -84168,8 +84013,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetConsoleOutputResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84216,8 +84060,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetConsoleScreenshotResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84265,8 +84108,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetHostReservationPurchasePreviewResultDeserializer::deserialize(
@@ -84315,8 +84157,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetLaunchTemplateDataResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84363,8 +84204,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetPasswordDataResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84412,8 +84252,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetReservedInstancesExchangeQuoteResultDeserializer::deserialize(
@@ -84462,8 +84301,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ImportImageResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84510,8 +84348,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ImportInstanceResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84558,8 +84395,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ImportKeyPairResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84606,8 +84442,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ImportSnapshotResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84654,8 +84489,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ImportVolumeResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84702,8 +84536,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ModifyFpgaImageAttributeResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84750,8 +84583,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ModifyHostsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -84911,8 +84743,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ModifyInstanceCreditSpecificationResultDeserializer::deserialize(
@@ -84961,8 +84792,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ModifyInstancePlacementResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -85009,8 +84839,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ModifyLaunchTemplateResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -85085,8 +84914,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ModifyReservedInstancesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -85161,8 +84989,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ModifySpotFleetRequestResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -85237,8 +85064,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ModifyVolumeResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -85341,8 +85167,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ModifyVpcEndpointResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -85396,8 +85221,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ModifyVpcEndpointConnectionNotificationResultDeserializer::deserialize(
@@ -85449,8 +85273,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ModifyVpcEndpointServiceConfigurationResultDeserializer::deserialize(
@@ -85502,8 +85325,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ModifyVpcEndpointServicePermissionsResultDeserializer::deserialize(
@@ -85553,8 +85375,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ModifyVpcPeeringConnectionOptionsResultDeserializer::deserialize(
@@ -85603,8 +85424,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ModifyVpcTenancyResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -85651,8 +85471,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(MonitorInstancesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -85699,8 +85518,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(MoveAddressToVpcResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -85747,8 +85565,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(PurchaseHostReservationResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -85796,8 +85613,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         PurchaseReservedInstancesOfferingResultDeserializer::deserialize(
@@ -85846,8 +85662,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(PurchaseScheduledInstancesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -85922,8 +85737,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(RegisterImageResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -85970,8 +85784,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         RejectVpcEndpointConnectionsResultDeserializer::deserialize(
@@ -86020,8 +85833,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(RejectVpcPeeringConnectionResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -86096,8 +85908,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ReleaseHostsResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -86147,8 +85958,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ReplaceIamInstanceProfileAssociationResultDeserializer::deserialize(
@@ -86197,8 +86007,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ReplaceNetworkAclAssociationResultDeserializer::deserialize(
@@ -86300,8 +86109,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ReplaceRouteTableAssociationResultDeserializer::deserialize(
@@ -86378,8 +86186,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(RequestSpotFleetResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -86426,8 +86233,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(RequestSpotInstancesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -86474,8 +86280,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ResetFpgaImageAttributeResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -86634,8 +86439,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(RestoreAddressToClassicResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -86738,8 +86542,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ReservationDeserializer::deserialize(
                         &actual_tag_name,
@@ -86786,8 +86589,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(RunScheduledInstancesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -86834,8 +86636,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(StartInstancesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -86882,8 +86683,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(StopInstancesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -86930,8 +86730,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(TerminateInstancesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -86978,8 +86777,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(UnassignIpv6AddressesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -87054,8 +86852,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(UnmonitorInstancesResultDeserializer::deserialize(
                         &actual_tag_name,
@@ -87109,8 +86906,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         UpdateSecurityGroupRuleDescriptionsEgressResultDeserializer::deserialize(
@@ -87166,8 +86962,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         UpdateSecurityGroupRuleDescriptionsIngressResultDeserializer::deserialize(
@@ -10004,7 +10004,7 @@ pub enum AssociateVPCWithHostedZoneError {
 impl AssociateVPCWithHostedZoneError {
     pub fn from_body(body: &str) -> AssociateVPCWithHostedZoneError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10119,7 +10119,7 @@ pub enum ChangeResourceRecordSetsError {
 impl ChangeResourceRecordSetsError {
     pub fn from_body(body: &str) -> ChangeResourceRecordSetsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10224,7 +10224,7 @@ pub enum ChangeTagsForResourceError {
 impl ChangeTagsForResourceError {
     pub fn from_body(body: &str) -> ChangeTagsForResourceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10323,7 +10323,7 @@ pub enum CreateHealthCheckError {
 impl CreateHealthCheckError {
     pub fn from_body(body: &str) -> CreateHealthCheckError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10426,7 +10426,7 @@ pub enum CreateHostedZoneError {
 impl CreateHostedZoneError {
     pub fn from_body(body: &str) -> CreateHostedZoneError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10545,7 +10545,7 @@ pub enum CreateQueryLoggingConfigError {
 impl CreateQueryLoggingConfigError {
     pub fn from_body(body: &str) -> CreateQueryLoggingConfigError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10664,7 +10664,7 @@ pub enum CreateReusableDelegationSetError {
 impl CreateReusableDelegationSetError {
     pub fn from_body(body: &str) -> CreateReusableDelegationSetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10779,7 +10779,7 @@ pub enum CreateTrafficPolicyError {
 impl CreateTrafficPolicyError {
     pub fn from_body(body: &str) -> CreateTrafficPolicyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10882,7 +10882,7 @@ pub enum CreateTrafficPolicyInstanceError {
 impl CreateTrafficPolicyInstanceError {
     pub fn from_body(body: &str) -> CreateTrafficPolicyInstanceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -10989,7 +10989,7 @@ pub enum CreateTrafficPolicyVersionError {
 impl CreateTrafficPolicyVersionError {
     pub fn from_body(body: &str) -> CreateTrafficPolicyVersionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11092,7 +11092,7 @@ pub enum CreateVPCAssociationAuthorizationError {
 impl CreateVPCAssociationAuthorizationError {
     pub fn from_body(body: &str) -> CreateVPCAssociationAuthorizationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11197,7 +11197,7 @@ pub enum DeleteHealthCheckError {
 impl DeleteHealthCheckError {
     pub fn from_body(body: &str) -> DeleteHealthCheckError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11292,7 +11292,7 @@ pub enum DeleteHostedZoneError {
 impl DeleteHostedZoneError {
     pub fn from_body(body: &str) -> DeleteHostedZoneError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11389,7 +11389,7 @@ pub enum DeleteQueryLoggingConfigError {
 impl DeleteQueryLoggingConfigError {
     pub fn from_body(body: &str) -> DeleteQueryLoggingConfigError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11484,7 +11484,7 @@ pub enum DeleteReusableDelegationSetError {
 impl DeleteReusableDelegationSetError {
     pub fn from_body(body: &str) -> DeleteReusableDelegationSetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11583,7 +11583,7 @@ pub enum DeleteTrafficPolicyError {
 impl DeleteTrafficPolicyError {
     pub fn from_body(body: &str) -> DeleteTrafficPolicyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11678,7 +11678,7 @@ pub enum DeleteTrafficPolicyInstanceError {
 impl DeleteTrafficPolicyInstanceError {
     pub fn from_body(body: &str) -> DeleteTrafficPolicyInstanceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11777,7 +11777,7 @@ pub enum DeleteVPCAssociationAuthorizationError {
 impl DeleteVPCAssociationAuthorizationError {
     pub fn from_body(body: &str) -> DeleteVPCAssociationAuthorizationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11886,7 +11886,7 @@ pub enum DisassociateVPCFromHostedZoneError {
 impl DisassociateVPCFromHostedZoneError {
     pub fn from_body(body: &str) -> DisassociateVPCFromHostedZoneError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -11983,7 +11983,7 @@ pub enum GetAccountLimitError {
 impl GetAccountLimitError {
     pub fn from_body(body: &str) -> GetAccountLimitError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12062,7 +12062,7 @@ pub enum GetChangeError {
 impl GetChangeError {
     pub fn from_body(body: &str) -> GetChangeError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12137,7 +12137,7 @@ pub enum GetCheckerIpRangesError {
 impl GetCheckerIpRangesError {
     pub fn from_body(body: &str) -> GetCheckerIpRangesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12214,7 +12214,7 @@ pub enum GetGeoLocationError {
 impl GetGeoLocationError {
     pub fn from_body(body: &str) -> GetGeoLocationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12299,7 +12299,7 @@ pub enum GetHealthCheckError {
 impl GetHealthCheckError {
     pub fn from_body(body: &str) -> GetHealthCheckError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12382,7 +12382,7 @@ pub enum GetHealthCheckCountError {
 impl GetHealthCheckCountError {
     pub fn from_body(body: &str) -> GetHealthCheckCountError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12459,7 +12459,7 @@ pub enum GetHealthCheckLastFailureReasonError {
 impl GetHealthCheckLastFailureReasonError {
     pub fn from_body(body: &str) -> GetHealthCheckLastFailureReasonError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12544,7 +12544,7 @@ pub enum GetHealthCheckStatusError {
 impl GetHealthCheckStatusError {
     pub fn from_body(body: &str) -> GetHealthCheckStatusError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12629,7 +12629,7 @@ pub enum GetHostedZoneError {
 impl GetHostedZoneError {
     pub fn from_body(body: &str) -> GetHostedZoneError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12710,7 +12710,7 @@ pub enum GetHostedZoneCountError {
 impl GetHostedZoneCountError {
     pub fn from_body(body: &str) -> GetHostedZoneCountError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12793,7 +12793,7 @@ pub enum GetHostedZoneLimitError {
 impl GetHostedZoneLimitError {
     pub fn from_body(body: &str) -> GetHostedZoneLimitError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12882,7 +12882,7 @@ pub enum GetQueryLoggingConfigError {
 impl GetQueryLoggingConfigError {
     pub fn from_body(body: &str) -> GetQueryLoggingConfigError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -12971,7 +12971,7 @@ pub enum GetReusableDelegationSetError {
 impl GetReusableDelegationSetError {
     pub fn from_body(body: &str) -> GetReusableDelegationSetError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13062,7 +13062,7 @@ pub enum GetReusableDelegationSetLimitError {
 impl GetReusableDelegationSetLimitError {
     pub fn from_body(body: &str) -> GetReusableDelegationSetLimitError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13147,7 +13147,7 @@ pub enum GetTrafficPolicyError {
 impl GetTrafficPolicyError {
     pub fn from_body(body: &str) -> GetTrafficPolicyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13230,7 +13230,7 @@ pub enum GetTrafficPolicyInstanceError {
 impl GetTrafficPolicyInstanceError {
     pub fn from_body(body: &str) -> GetTrafficPolicyInstanceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13313,7 +13313,7 @@ pub enum GetTrafficPolicyInstanceCountError {
 impl GetTrafficPolicyInstanceCountError {
     pub fn from_body(body: &str) -> GetTrafficPolicyInstanceCountError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13388,7 +13388,7 @@ pub enum ListGeoLocationsError {
 impl ListGeoLocationsError {
     pub fn from_body(body: &str) -> ListGeoLocationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13467,7 +13467,7 @@ pub enum ListHealthChecksError {
 impl ListHealthChecksError {
     pub fn from_body(body: &str) -> ListHealthChecksError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13552,7 +13552,7 @@ pub enum ListHostedZonesError {
 impl ListHostedZonesError {
     pub fn from_body(body: &str) -> ListHostedZonesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13639,7 +13639,7 @@ pub enum ListHostedZonesByNameError {
 impl ListHostedZonesByNameError {
     pub fn from_body(body: &str) -> ListHostedZonesByNameError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13726,7 +13726,7 @@ pub enum ListQueryLoggingConfigsError {
 impl ListQueryLoggingConfigsError {
     pub fn from_body(body: &str) -> ListQueryLoggingConfigsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13815,7 +13815,7 @@ pub enum ListResourceRecordSetsError {
 impl ListResourceRecordSetsError {
     pub fn from_body(body: &str) -> ListResourceRecordSetsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13898,7 +13898,7 @@ pub enum ListReusableDelegationSetsError {
 impl ListReusableDelegationSetsError {
     pub fn from_body(body: &str) -> ListReusableDelegationSetsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -13985,7 +13985,7 @@ pub enum ListTagsForResourceError {
 impl ListTagsForResourceError {
     pub fn from_body(body: &str) -> ListTagsForResourceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -14088,7 +14088,7 @@ pub enum ListTagsForResourcesError {
 impl ListTagsForResourcesError {
     pub fn from_body(body: &str) -> ListTagsForResourcesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -14183,7 +14183,7 @@ pub enum ListTrafficPoliciesError {
 impl ListTrafficPoliciesError {
     pub fn from_body(body: &str) -> ListTrafficPoliciesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -14264,7 +14264,7 @@ pub enum ListTrafficPolicyInstancesError {
 impl ListTrafficPolicyInstancesError {
     pub fn from_body(body: &str) -> ListTrafficPolicyInstancesError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -14353,7 +14353,7 @@ pub enum ListTrafficPolicyInstancesByHostedZoneError {
 impl ListTrafficPolicyInstancesByHostedZoneError {
     pub fn from_body(body: &str) -> ListTrafficPolicyInstancesByHostedZoneError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -14450,7 +14450,7 @@ pub enum ListTrafficPolicyInstancesByPolicyError {
 impl ListTrafficPolicyInstancesByPolicyError {
     pub fn from_body(body: &str) -> ListTrafficPolicyInstancesByPolicyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -14545,7 +14545,7 @@ pub enum ListTrafficPolicyVersionsError {
 impl ListTrafficPolicyVersionsError {
     pub fn from_body(body: &str) -> ListTrafficPolicyVersionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -14632,7 +14632,7 @@ pub enum ListVPCAssociationAuthorizationsError {
 impl ListVPCAssociationAuthorizationsError {
     pub fn from_body(body: &str) -> ListVPCAssociationAuthorizationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -14723,7 +14723,7 @@ pub enum TestDNSAnswerError {
 impl TestDNSAnswerError {
     pub fn from_body(body: &str) -> TestDNSAnswerError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -14808,7 +14808,7 @@ pub enum UpdateHealthCheckError {
 impl UpdateHealthCheckError {
     pub fn from_body(body: &str) -> UpdateHealthCheckError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -14899,7 +14899,7 @@ pub enum UpdateHostedZoneCommentError {
 impl UpdateHostedZoneCommentError {
     pub fn from_body(body: &str) -> UpdateHostedZoneCommentError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -14986,7 +14986,7 @@ pub enum UpdateTrafficPolicyCommentError {
 impl UpdateTrafficPolicyCommentError {
     pub fn from_body(body: &str) -> UpdateTrafficPolicyCommentError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -15083,7 +15083,7 @@ pub enum UpdateTrafficPolicyInstanceError {
 impl UpdateTrafficPolicyInstanceError {
     pub fn from_body(body: &str) -> UpdateTrafficPolicyInstanceError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -15601,8 +15601,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         AssociateVPCWithHostedZoneResponseDeserializer::deserialize(
@@ -15663,8 +15662,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ChangeResourceRecordSetsResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -15724,8 +15722,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ChangeTagsForResourceResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -15781,8 +15778,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateHealthCheckResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -15840,8 +15836,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateHostedZoneResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -15899,8 +15894,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateQueryLoggingConfigResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -15958,8 +15952,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CreateReusableDelegationSetResponseDeserializer::deserialize(
@@ -16019,8 +16012,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateTrafficPolicyResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -16078,8 +16070,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CreateTrafficPolicyInstanceResponseDeserializer::deserialize(
@@ -16139,8 +16130,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CreateTrafficPolicyVersionResponseDeserializer::deserialize(
@@ -16206,8 +16196,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         CreateVPCAssociationAuthorizationResponseDeserializer::deserialize(
@@ -16258,8 +16247,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteHealthCheckResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -16305,8 +16293,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteHostedZoneResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -16352,8 +16339,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteQueryLoggingConfigResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -16399,8 +16385,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DeleteReusableDelegationSetResponseDeserializer::deserialize(
@@ -16452,8 +16437,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteTrafficPolicyResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -16499,8 +16483,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DeleteTrafficPolicyInstanceResponseDeserializer::deserialize(
@@ -16564,8 +16547,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DeleteVPCAssociationAuthorizationResponseDeserializer::deserialize(
@@ -16627,8 +16609,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         DisassociateVPCFromHostedZoneResponseDeserializer::deserialize(
@@ -16676,8 +16657,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetAccountLimitResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -16723,8 +16703,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetChangeResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -16770,8 +16749,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetCheckerIpRangesResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -16829,8 +16807,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetGeoLocationResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -16879,8 +16856,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetHealthCheckResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -16926,8 +16902,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetHealthCheckCountResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -16977,8 +16952,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetHealthCheckLastFailureReasonResponseDeserializer::deserialize(
@@ -17029,8 +17003,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetHealthCheckStatusResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17076,8 +17049,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetHostedZoneResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17123,8 +17095,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetHostedZoneCountResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17170,8 +17141,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetHostedZoneLimitResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17217,8 +17187,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetQueryLoggingConfigResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17264,8 +17233,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetReusableDelegationSetResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17312,8 +17280,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetReusableDelegationSetLimitResponseDeserializer::deserialize(
@@ -17365,8 +17332,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetTrafficPolicyResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17412,8 +17378,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetTrafficPolicyInstanceResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17460,8 +17425,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetTrafficPolicyInstanceCountResponseDeserializer::deserialize(
@@ -17524,8 +17488,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListGeoLocationsResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17580,8 +17543,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListHealthChecksResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17639,8 +17601,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListHostedZonesResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17698,8 +17659,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListHostedZonesByNameResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17757,8 +17717,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListQueryLoggingConfigsResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17822,8 +17781,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListResourceRecordSetsResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17878,8 +17836,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ListReusableDelegationSetsResponseDeserializer::deserialize(
@@ -17931,8 +17888,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListTagsForResourceResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -17991,8 +17947,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListTagsForResourcesResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -18047,8 +18002,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListTrafficPoliciesResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -18109,8 +18063,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ListTrafficPolicyInstancesResponseDeserializer::deserialize(
@@ -18174,8 +18127,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ListTrafficPolicyInstancesByHostedZoneResponseDeserializer::deserialize(
@@ -18243,8 +18195,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ListTrafficPolicyInstancesByPolicyResponseDeserializer::deserialize(
@@ -18301,8 +18252,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListTrafficPolicyVersionsResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -18361,8 +18311,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ListVPCAssociationAuthorizationsResponseDeserializer::deserialize(
@@ -18425,8 +18374,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(TestDNSAnswerResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -18485,8 +18433,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(UpdateHealthCheckResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -18542,8 +18489,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(UpdateHostedZoneCommentResponseDeserializer::deserialize(
                         &actual_tag_name,
@@ -18603,8 +18549,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         UpdateTrafficPolicyCommentResponseDeserializer::deserialize(
@@ -18662,8 +18607,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         UpdateTrafficPolicyInstanceResponseDeserializer::deserialize(
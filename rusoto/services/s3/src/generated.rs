@@ -15578,7 +15578,7 @@ pub enum AbortMultipartUploadError {
 impl AbortMultipartUploadError {
     pub fn from_body(body: &str) -> AbortMultipartUploadError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -15654,7 +15654,7 @@ pub enum CompleteMultipartUploadError {
 impl CompleteMultipartUploadError {
     pub fn from_body(body: &str) -> CompleteMultipartUploadError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -15728,7 +15728,7 @@ pub enum CopyObjectError {
 impl CopyObjectError {
     pub fn from_body(body: &str) -> CopyObjectError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -15806,7 +15806,7 @@ pub enum CreateBucketError {
 impl CreateBucketError {
     pub fn from_body(body: &str) -> CreateBucketError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -15884,7 +15884,7 @@ pub enum CreateMultipartUploadError {
 impl CreateMultipartUploadError {
     pub fn from_body(body: &str) -> CreateMultipartUploadError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -15956,7 +15956,7 @@ pub enum DeleteBucketError {
 impl DeleteBucketError {
     pub fn from_body(body: &str) -> DeleteBucketError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16026,7 +16026,7 @@ pub enum DeleteBucketAnalyticsConfigurationError {
 impl DeleteBucketAnalyticsConfigurationError {
     pub fn from_body(body: &str) -> DeleteBucketAnalyticsConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16098,7 +16098,7 @@ pub enum DeleteBucketCorsError {
 impl DeleteBucketCorsError {
     pub fn from_body(body: &str) -> DeleteBucketCorsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16168,7 +16168,7 @@ pub enum DeleteBucketEncryptionError {
 impl DeleteBucketEncryptionError {
     pub fn from_body(body: &str) -> DeleteBucketEncryptionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16240,7 +16240,7 @@ pub enum DeleteBucketInventoryConfigurationError {
 impl DeleteBucketInventoryConfigurationError {
     pub fn from_body(body: &str) -> DeleteBucketInventoryConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16312,7 +16312,7 @@ pub enum DeleteBucketLifecycleError {
 impl DeleteBucketLifecycleError {
     pub fn from_body(body: &str) -> DeleteBucketLifecycleError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16384,7 +16384,7 @@ pub enum DeleteBucketMetricsConfigurationError {
 impl DeleteBucketMetricsConfigurationError {
     pub fn from_body(body: &str) -> DeleteBucketMetricsConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16456,7 +16456,7 @@ pub enum DeleteBucketPolicyError {
 impl DeleteBucketPolicyError {
     pub fn from_body(body: &str) -> DeleteBucketPolicyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16528,7 +16528,7 @@ pub enum DeleteBucketReplicationError {
 impl DeleteBucketReplicationError {
     pub fn from_body(body: &str) -> DeleteBucketReplicationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16600,7 +16600,7 @@ pub enum DeleteBucketTaggingError {
 impl DeleteBucketTaggingError {
     pub fn from_body(body: &str) -> DeleteBucketTaggingError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16672,7 +16672,7 @@ pub enum DeleteBucketWebsiteError {
 impl DeleteBucketWebsiteError {
     pub fn from_body(body: &str) -> DeleteBucketWebsiteError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16744,7 +16744,7 @@ pub enum DeleteObjectError {
 impl DeleteObjectError {
     pub fn from_body(body: &str) -> DeleteObjectError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16814,7 +16814,7 @@ pub enum DeleteObjectTaggingError {
 impl DeleteObjectTaggingError {
     pub fn from_body(body: &str) -> DeleteObjectTaggingError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16886,7 +16886,7 @@ pub enum DeleteObjectsError {
 impl DeleteObjectsError {
     pub fn from_body(body: &str) -> DeleteObjectsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -16956,7 +16956,7 @@ pub enum GetBucketAccelerateConfigurationError {
 impl GetBucketAccelerateConfigurationError {
     pub fn from_body(body: &str) -> GetBucketAccelerateConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17028,7 +17028,7 @@ pub enum GetBucketAclError {
 impl GetBucketAclError {
     pub fn from_body(body: &str) -> GetBucketAclError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17098,7 +17098,7 @@ pub enum GetBucketAnalyticsConfigurationError {
 impl GetBucketAnalyticsConfigurationError {
     pub fn from_body(body: &str) -> GetBucketAnalyticsConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17170,7 +17170,7 @@ pub enum GetBucketCorsError {
 impl GetBucketCorsError {
     pub fn from_body(body: &str) -> GetBucketCorsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17240,7 +17240,7 @@ pub enum GetBucketEncryptionError {
 impl GetBucketEncryptionError {
     pub fn from_body(body: &str) -> GetBucketEncryptionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17312,7 +17312,7 @@ pub enum GetBucketInventoryConfigurationError {
 impl GetBucketInventoryConfigurationError {
     pub fn from_body(body: &str) -> GetBucketInventoryConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17384,7 +17384,7 @@ pub enum GetBucketLifecycleError {
 impl GetBucketLifecycleError {
     pub fn from_body(body: &str) -> GetBucketLifecycleError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17456,7 +17456,7 @@ pub enum GetBucketLifecycleConfigurationError {
 impl GetBucketLifecycleConfigurationError {
     pub fn from_body(body: &str) -> GetBucketLifecycleConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17528,7 +17528,7 @@ pub enum GetBucketLocationError {
 impl GetBucketLocationError {
     pub fn from_body(body: &str) -> GetBucketLocationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17600,7 +17600,7 @@ pub enum GetBucketLoggingError {
 impl GetBucketLoggingError {
     pub fn from_body(body: &str) -> GetBucketLoggingError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17670,7 +17670,7 @@ pub enum GetBucketMetricsConfigurationError {
 impl GetBucketMetricsConfigurationError {
     pub fn from_body(body: &str) -> GetBucketMetricsConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17742,7 +17742,7 @@ pub enum GetBucketNotificationError {
 impl GetBucketNotificationError {
     pub fn from_body(body: &str) -> GetBucketNotificationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17814,7 +17814,7 @@ pub enum GetBucketNotificationConfigurationError {
 impl GetBucketNotificationConfigurationError {
     pub fn from_body(body: &str) -> GetBucketNotificationConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17886,7 +17886,7 @@ pub enum GetBucketPolicyError {
 impl GetBucketPolicyError {
     pub fn from_body(body: &str) -> GetBucketPolicyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -17956,7 +17956,7 @@ pub enum GetBucketReplicationError {
 impl GetBucketReplicationError {
     pub fn from_body(body: &str) -> GetBucketReplicationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18028,7 +18028,7 @@ pub enum GetBucketRequestPaymentError {
 impl GetBucketRequestPaymentError {
     pub fn from_body(body: &str) -> GetBucketRequestPaymentError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18100,7 +18100,7 @@ pub enum GetBucketTaggingError {
 impl GetBucketTaggingError {
     pub fn from_body(body: &str) -> GetBucketTaggingError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18170,7 +18170,7 @@ pub enum GetBucketVersioningError {
 impl GetBucketVersioningError {
     pub fn from_body(body: &str) -> GetBucketVersioningError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18242,7 +18242,7 @@ pub enum GetBucketWebsiteError {
 impl GetBucketWebsiteError {
     pub fn from_body(body: &str) -> GetBucketWebsiteError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18314,7 +18314,7 @@ pub enum GetObjectError {
 impl GetObjectError {
     pub fn from_body(body: &str) -> GetObjectError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18388,7 +18388,7 @@ pub enum GetObjectAclError {
 impl GetObjectAclError {
     pub fn from_body(body: &str) -> GetObjectAclError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18460,7 +18460,7 @@ pub enum GetObjectTaggingError {
 impl GetObjectTaggingError {
     pub fn from_body(body: &str) -> GetObjectTaggingError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18530,7 +18530,7 @@ pub enum GetObjectTorrentError {
 impl GetObjectTorrentError {
     pub fn from_body(body: &str) -> GetObjectTorrentError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18602,7 +18602,7 @@ pub enum HeadBucketError {
 impl HeadBucketError {
     pub fn from_body(body: &str) -> HeadBucketError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18676,7 +18676,7 @@ pub enum HeadObjectError {
 impl HeadObjectError {
     pub fn from_body(body: &str) -> HeadObjectError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18748,7 +18748,7 @@ pub enum ListBucketAnalyticsConfigurationsError {
 impl ListBucketAnalyticsConfigurationsError {
     pub fn from_body(body: &str) -> ListBucketAnalyticsConfigurationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18820,7 +18820,7 @@ pub enum ListBucketInventoryConfigurationsError {
 impl ListBucketInventoryConfigurationsError {
     pub fn from_body(body: &str) -> ListBucketInventoryConfigurationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18892,7 +18892,7 @@ pub enum ListBucketMetricsConfigurationsError {
 impl ListBucketMetricsConfigurationsError {
     pub fn from_body(body: &str) -> ListBucketMetricsConfigurationsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -18964,7 +18964,7 @@ pub enum ListBucketsError {
 impl ListBucketsError {
     pub fn from_body(body: &str) -> ListBucketsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19034,7 +19034,7 @@ pub enum ListMultipartUploadsError {
 impl ListMultipartUploadsError {
     pub fn from_body(body: &str) -> ListMultipartUploadsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19106,7 +19106,7 @@ pub enum ListObjectVersionsError {
 impl ListObjectVersionsError {
     pub fn from_body(body: &str) -> ListObjectVersionsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19180,7 +19180,7 @@ pub enum ListObjectsError {
 impl ListObjectsError {
     pub fn from_body(body: &str) -> ListObjectsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19256,7 +19256,7 @@ pub enum ListObjectsV2Error {
 impl ListObjectsV2Error {
     pub fn from_body(body: &str) -> ListObjectsV2Error {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19330,7 +19330,7 @@ pub enum ListPartsError {
 impl ListPartsError {
     pub fn from_body(body: &str) -> ListPartsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19400,7 +19400,7 @@ pub enum PutBucketAccelerateConfigurationError {
 impl PutBucketAccelerateConfigurationError {
     pub fn from_body(body: &str) -> PutBucketAccelerateConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19472,7 +19472,7 @@ pub enum PutBucketAclError {
 impl PutBucketAclError {
     pub fn from_body(body: &str) -> PutBucketAclError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19542,7 +19542,7 @@ pub enum PutBucketAnalyticsConfigurationError {
 impl PutBucketAnalyticsConfigurationError {
     pub fn from_body(body: &str) -> PutBucketAnalyticsConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19614,7 +19614,7 @@ pub enum PutBucketCorsError {
 impl PutBucketCorsError {
     pub fn from_body(body: &str) -> PutBucketCorsError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19684,7 +19684,7 @@ pub enum PutBucketEncryptionError {
 impl PutBucketEncryptionError {
     pub fn from_body(body: &str) -> PutBucketEncryptionError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19756,7 +19756,7 @@ pub enum PutBucketInventoryConfigurationError {
 impl PutBucketInventoryConfigurationError {
     pub fn from_body(body: &str) -> PutBucketInventoryConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19828,7 +19828,7 @@ pub enum PutBucketLifecycleError {
 impl PutBucketLifecycleError {
     pub fn from_body(body: &str) -> PutBucketLifecycleError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19900,7 +19900,7 @@ pub enum PutBucketLifecycleConfigurationError {
 impl PutBucketLifecycleConfigurationError {
     pub fn from_body(body: &str) -> PutBucketLifecycleConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -19972,7 +19972,7 @@ pub enum PutBucketLoggingError {
 impl PutBucketLoggingError {
     pub fn from_body(body: &str) -> PutBucketLoggingError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20042,7 +20042,7 @@ pub enum PutBucketMetricsConfigurationError {
 impl PutBucketMetricsConfigurationError {
     pub fn from_body(body: &str) -> PutBucketMetricsConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20114,7 +20114,7 @@ pub enum PutBucketNotificationError {
 impl PutBucketNotificationError {
     pub fn from_body(body: &str) -> PutBucketNotificationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20186,7 +20186,7 @@ pub enum PutBucketNotificationConfigurationError {
 impl PutBucketNotificationConfigurationError {
     pub fn from_body(body: &str) -> PutBucketNotificationConfigurationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20258,7 +20258,7 @@ pub enum PutBucketPolicyError {
 impl PutBucketPolicyError {
     pub fn from_body(body: &str) -> PutBucketPolicyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20328,7 +20328,7 @@ pub enum PutBucketReplicationError {
 impl PutBucketReplicationError {
     pub fn from_body(body: &str) -> PutBucketReplicationError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20400,7 +20400,7 @@ pub enum PutBucketRequestPaymentError {
 impl PutBucketRequestPaymentError {
     pub fn from_body(body: &str) -> PutBucketRequestPaymentError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20472,7 +20472,7 @@ pub enum PutBucketTaggingError {
 impl PutBucketTaggingError {
     pub fn from_body(body: &str) -> PutBucketTaggingError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20542,7 +20542,7 @@ pub enum PutBucketVersioningError {
 impl PutBucketVersioningError {
     pub fn from_body(body: &str) -> PutBucketVersioningError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20614,7 +20614,7 @@ pub enum PutBucketWebsiteError {
 impl PutBucketWebsiteError {
     pub fn from_body(body: &str) -> PutBucketWebsiteError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20684,7 +20684,7 @@ pub enum PutObjectError {
 impl PutObjectError {
     pub fn from_body(body: &str) -> PutObjectError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20756,7 +20756,7 @@ pub enum PutObjectAclError {
 impl PutObjectAclError {
     pub fn from_body(body: &str) -> PutObjectAclError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20828,7 +20828,7 @@ pub enum PutObjectTaggingError {
 impl PutObjectTaggingError {
     pub fn from_body(body: &str) -> PutObjectTaggingError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20900,7 +20900,7 @@ pub enum RestoreObjectError {
 impl RestoreObjectError {
     pub fn from_body(body: &str) -> RestoreObjectError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -20976,7 +20976,7 @@ pub enum UploadPartError {
 impl UploadPartError {
     pub fn from_body(body: &str) -> UploadPartError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -21046,7 +21046,7 @@ pub enum UploadPartCopyError {
 impl UploadPartCopyError {
     pub fn from_body(body: &str) -> UploadPartCopyError {
         let reader = EventReader::new(body.as_bytes());
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+        let mut stack = XmlResponse::new(reader);
         find_start_element(&mut stack);
         match Self::deserialize(&mut stack) {
             Ok(parsed_error) => match &parsed_error.code[..] {
@@ -21625,8 +21625,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(AbortMultipartUploadOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -21697,8 +21696,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CompleteMultipartUploadOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -21934,8 +21932,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CopyObjectOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -22065,8 +22062,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateBucketOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -22224,8 +22220,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(CreateMultipartUploadOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -22660,8 +22655,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteObjectOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -22726,8 +22720,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteObjectTaggingOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -22796,8 +22789,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(DeleteObjectsOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -22852,8 +22844,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetBucketAccelerateConfigurationOutputDeserializer::deserialize(
@@ -22905,8 +22896,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetBucketAclOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -22958,8 +22948,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetBucketAnalyticsConfigurationOutputDeserializer::deserialize(
@@ -23011,8 +23000,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetBucketCorsOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -23062,8 +23050,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetBucketEncryptionOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -23115,8 +23102,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetBucketInventoryConfigurationOutputDeserializer::deserialize(
@@ -23168,8 +23154,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetBucketLifecycleOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -23220,8 +23205,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetBucketLifecycleConfigurationOutputDeserializer::deserialize(
@@ -23273,8 +23257,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetBucketLocationOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -23324,8 +23307,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetBucketLoggingOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -23376,8 +23358,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         GetBucketMetricsConfigurationOutputDeserializer::deserialize(
@@ -23429,8 +23410,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         NotificationConfigurationDeprecatedDeserializer::deserialize(
@@ -23482,8 +23462,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(NotificationConfigurationDeserializer::deserialize(
                         &actual_tag_name,
@@ -23569,8 +23548,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetBucketReplicationOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -23620,8 +23598,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetBucketRequestPaymentOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -23671,8 +23648,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetBucketTaggingOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -23722,8 +23698,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetBucketVersioningOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -23773,8 +23748,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetBucketWebsiteOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -24056,8 +24030,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetObjectAclOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -24114,8 +24087,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(GetObjectTaggingOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -24284,8 +24256,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(HeadObjectOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -24455,8 +24426,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ListBucketAnalyticsConfigurationsOutputDeserializer::deserialize(
@@ -24512,8 +24482,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ListBucketInventoryConfigurationsOutputDeserializer::deserialize(
@@ -24569,8 +24538,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(
                         ListBucketMetricsConfigurationsOutputDeserializer::deserialize(
@@ -24615,8 +24583,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListBucketsOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -24684,8 +24651,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListMultipartUploadsOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -24753,8 +24719,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListObjectVersionsOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -24821,8 +24786,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListObjectsOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -24896,8 +24860,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListObjectsV2OutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -24956,8 +24919,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(ListPartsOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -25957,8 +25919,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(PutObjectOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -26099,8 +26060,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(PutObjectAclOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -26166,8 +26126,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(PutObjectTaggingOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -26241,8 +26200,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(RestoreObjectOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -26338,8 +26296,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(UploadPartOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -26506,8 +26463,7 @@ where
                         response.body.as_slice(),
                         ParserConfig::new().trim_whitespace(true),
                     );
-                    let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                    let _start_document = stack.next();
+                    let mut stack = XmlResponse::new(reader);
                     let actual_tag_name = try!(peek_at_name(&mut stack));
                     result = try!(UploadPartCopyOutputDeserializer::deserialize(
                         &actual_tag_name,
@@ -1,11 +1,18 @@
+use std::env;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
 use chrono::prelude::*;
 use chrono::Duration;
 use futures::{Async, Future, Poll};
+use tokio_core::reactor::Handle;
 
 use rusoto_core;
 
-use rusoto_core::{AwsCredentials, CredentialsError,
-    ProvideAwsCredentials, DispatchSignedRequest, RusotoFuture};
+use rusoto_core::{AwsCredentials, ChainProvider, ContainerProvider, CredentialsError,
+    EnvironmentProvider, InstanceMetadataProvider, ProfileProvider, ProvideAwsCredentials,
+    DispatchSignedRequest, Region, RusotoFuture, SignedRequest};
+use rusoto_core::credential::RoleSource;
 use ::{AssumeRoleRequest, AssumeRoleResponse, AssumeRoleError,
     AssumeRoleWithSAMLRequest, AssumeRoleWithSAMLResponse, AssumeRoleWithSAMLError,
     AssumeRoleWithWebIdentityRequest, AssumeRoleWithWebIdentityResponse, AssumeRoleWithWebIdentityError,
@@ -17,6 +24,10 @@ use ::{AssumeRoleRequest, AssumeRoleResponse, AssumeRoleError,
 
 pub const DEFAULT_DURATION_SECONDS: i32 = 3600;
 pub const DEFAULT_ROLE_DURATION_SECONDS: i32 = 900;
+/// The environment variable holding the ARN of the role to assume.
+pub const AWS_ROLE_ARN: &str = "AWS_ROLE_ARN";
+/// The environment variable holding the session name used when assuming the role.
+pub const AWS_ROLE_SESSION_NAME: &str = "AWS_ROLE_SESSION_NAME";
 
 /// Trait for conversions from STS Credentials to AWS Credentials.
 pub trait NewAwsCredsForStsCreds {
@@ -232,6 +243,23 @@ impl StsAssumeRoleSessionCredentialsProvider {
         self.mfa_code = None;
     }
 
+    /// Creates a new `StsAssumeRoleSessionCredentialsProvider` assuming the role given by the
+    /// `AWS_ROLE_ARN` environment variable, or `None` if it isn't set.
+    ///
+    /// The session name is taken from `AWS_ROLE_SESSION_NAME`. If it isn't set, a session
+    /// name containing the current time is used.
+    pub fn from_env<P,D>(sts_client: StsClient<P,D>) -> Option<StsAssumeRoleSessionCredentialsProvider>
+            where P: ProvideAwsCredentials + 'static, D: DispatchSignedRequest + 'static {
+        let role_arn = match non_empty_env_var(AWS_ROLE_ARN) {
+            Some(role_arn) => role_arn,
+            None => return None,
+        };
+        let session_name = non_empty_env_var(AWS_ROLE_SESSION_NAME)
+            .unwrap_or_else(|| format!("rusoto-{}", Utc::now().timestamp()));
+        Some(StsAssumeRoleSessionCredentialsProvider::new(
+            sts_client, role_arn, session_name, None, None, None, None))
+    }
+
     /// Calls `AssumeRole` to get a session token from the STS Api.
     /// Optionally uses MFA if the MFA serial number and code are set.
     pub fn assume_role(&self) -> StsAssumeRoleSessionCredentialsProviderFuture {
@@ -386,3 +414,282 @@ impl ProvideAwsCredentials for StsWebIdentityFederationSessionCredentialsProvide
         self.assume_role_with_web_identity()
     }
 }
+
+/// [AwsCredentials](../rusoto_credential/struct.AwsCredentials.html) provider that assumes
+/// the role given by the `AWS_ROLE_ARN` environment variable, using the base credentials from
+/// the environment variables `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY`, and uses a
+/// [ChainProvider](../rusoto_credential/struct.ChainProvider.html) otherwise.
+///
+/// The environment is read when the provider is created. See
+/// `StsAssumeRoleSessionCredentialsProvider::from_env` for how the role session is named.
+pub struct StsEnvRoleChainProvider {
+    role_provider: Option<StsAssumeRoleSessionCredentialsProvider>,
+    chain_provider: ChainProvider,
+}
+
+impl StsEnvRoleChainProvider {
+    /// Creates a new `StsEnvRoleChainProvider`, which calls `AssumeRole` in the given region
+    /// using the given request dispatcher.
+    pub fn new<D>(request_dispatcher: D, region: Region, handle: &Handle) -> StsEnvRoleChainProvider
+            where D: DispatchSignedRequest + 'static {
+        StsEnvRoleChainProvider::with_chain_provider(request_dispatcher, region, ChainProvider::new(handle))
+    }
+
+    /// Creates a new `StsEnvRoleChainProvider` like `new`, which uses the given `ChainProvider`
+    /// if no role is to be assumed.
+    pub fn with_chain_provider<D>(request_dispatcher: D,
+            region: Region,
+            chain_provider: ChainProvider)
+            -> StsEnvRoleChainProvider
+            where D: DispatchSignedRequest + 'static {
+        let role_provider = if EnvironmentProvider.credentials().wait().is_ok() {
+            let sts_client = StsClient::new(request_dispatcher, EnvironmentProvider, region);
+            StsAssumeRoleSessionCredentialsProvider::from_env(sts_client)
+        } else {
+            None
+        };
+        StsEnvRoleChainProvider {
+            role_provider: role_provider,
+            chain_provider: chain_provider,
+        }
+    }
+
+    /// Whether a role is assumed, i.e. `AWS_ROLE_ARN` and base credentials were found in
+    /// the environment.
+    pub fn assumes_role(&self) -> bool {
+        self.role_provider.is_some()
+    }
+}
+
+pub struct StsEnvRoleChainProviderFuture {
+    inner: Box<Future<Item=AwsCredentials, Error=CredentialsError>>
+}
+
+impl Future for StsEnvRoleChainProviderFuture {
+    type Item = AwsCredentials;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+impl ProvideAwsCredentials for StsEnvRoleChainProvider {
+    type Future = StsEnvRoleChainProviderFuture;
+
+    fn credentials(&self) -> Self::Future {
+        let inner: Box<Future<Item=AwsCredentials, Error=CredentialsError>> = match self.role_provider {
+            Some(ref provider) => Box::new(provider.credentials()),
+            None => Box::new(self.chain_provider.credentials()),
+        };
+        StsEnvRoleChainProviderFuture { inner: inner }
+    }
+}
+
+/// [AwsCredentials](../rusoto_credential/struct.AwsCredentials.html) provider for the
+/// profile of a [ProfileProvider](../rusoto_credential/struct.ProfileProvider.html),
+/// which assumes the profile's `role_arn` if it has one.
+///
+/// The base credentials for assuming the role are taken from the `source_profile`, which
+/// may assume a role itself, or the `credential_source`. The role session is named like
+/// by `StsAssumeRoleSessionCredentialsProvider::from_env`. The profiles are read when the
+/// provider is created.
+pub struct StsProfileRoleProvider {
+    inner: ProfileRoleCredentials,
+}
+
+enum ProfileRoleCredentials {
+    Profile(ProfileProvider),
+    Role(StsAssumeRoleSessionCredentialsProvider),
+}
+
+impl StsProfileRoleProvider {
+    /// Creates a new `StsProfileRoleProvider` for the profile selected by `AWS_PROFILE` in
+    /// the default credentials and config files, like `ProfileProvider::new`, which calls
+    /// `AssumeRole` in the given region using the given request dispatcher.
+    pub fn new<D>(request_dispatcher: D, region: Region, handle: &Handle)
+            -> Result<StsProfileRoleProvider, CredentialsError>
+            where D: DispatchSignedRequest + 'static {
+        let profile_provider = try!(ProfileProvider::new());
+        StsProfileRoleProvider::with_profile_provider(request_dispatcher, region, profile_provider, handle)
+    }
+
+    /// Creates a new `StsProfileRoleProvider` like `new`, for the profile of the given
+    /// `ProfileProvider`.
+    pub fn with_profile_provider<D>(request_dispatcher: D,
+            region: Region,
+            profile_provider: ProfileProvider,
+            handle: &Handle)
+            -> Result<StsProfileRoleProvider, CredentialsError>
+            where D: DispatchSignedRequest + 'static {
+        StsProfileRoleProvider::with_credential_sources(
+            request_dispatcher, region, profile_provider, &DefaultCredentialSources, handle)
+    }
+
+    /// Creates a new `StsProfileRoleProvider` like `with_profile_provider`, taking the base
+    /// credentials of a `credential_source` from `sources`.
+    pub(crate) fn with_credential_sources<D, S>(request_dispatcher: D,
+            region: Region,
+            profile_provider: ProfileProvider,
+            sources: &S,
+            handle: &Handle)
+            -> Result<StsProfileRoleProvider, CredentialsError>
+            where D: DispatchSignedRequest + 'static,
+                  S: CredentialSources {
+        let request_dispatcher = SharedDispatcher(Arc::new(request_dispatcher));
+        let inner = try!(profile_role_credentials(
+            request_dispatcher, region, profile_provider, sources, handle, &mut Vec::new()));
+        Ok(StsProfileRoleProvider { inner: inner })
+    }
+
+    /// Whether the profile assumes a role.
+    pub fn assumes_role(&self) -> bool {
+        match self.inner {
+            ProfileRoleCredentials::Profile(_) => false,
+            ProfileRoleCredentials::Role(_) => true,
+        }
+    }
+}
+
+/// Resolves how to get the credentials of the profile of `profile_provider`, following the
+/// chain of source profiles. `visited` holds the profiles already on the chain.
+fn profile_role_credentials<D, S>(request_dispatcher: SharedDispatcher<D>,
+        region: Region,
+        profile_provider: ProfileProvider,
+        sources: &S,
+        handle: &Handle,
+        visited: &mut Vec<String>)
+        -> Result<ProfileRoleCredentials, CredentialsError>
+        where D: DispatchSignedRequest + 'static,
+              S: CredentialSources {
+    let profile = profile_provider.profile().to_owned();
+    if visited.contains(&profile) {
+        return Err(CredentialsError::new(format!(
+            "Cycle in source_profile chain: {} -> {}", visited.join(" -> "), profile)));
+    }
+    visited.push(profile);
+
+    let (role_arn, source) = match try!(profile_provider.role_source()) {
+        Some(role) => role,
+        None => return Ok(ProfileRoleCredentials::Profile(profile_provider)),
+    };
+    let sts_client: Box<StsSessionCredentialsClient> = match source {
+        RoleSource::Profile(source_profile) => {
+            let mut source_provider = profile_provider.clone();
+            source_provider.set_profile(source_profile);
+            let base = try!(profile_role_credentials(
+                request_dispatcher.clone(), region.clone(), source_provider, sources, handle, visited));
+            let base = StsProfileRoleProvider { inner: base };
+            Box::new(StsClient::new(request_dispatcher, base, region))
+        }
+        RoleSource::Environment => {
+            Box::new(StsClient::new(request_dispatcher, sources.environment(), region))
+        }
+        RoleSource::Ec2InstanceMetadata => {
+            let base = sources.ec2_instance_metadata(handle);
+            Box::new(StsClient::new(request_dispatcher, base, region))
+        }
+        RoleSource::EcsContainer => {
+            let base = sources.ecs_container(handle);
+            Box::new(StsClient::new(request_dispatcher, base, region))
+        }
+    };
+    let role_provider = StsAssumeRoleSessionCredentialsProvider {
+        sts_client: sts_client,
+        role_arn: role_arn,
+        session_name: non_empty_env_var(AWS_ROLE_SESSION_NAME)
+            .unwrap_or_else(|| format!("rusoto-{}", Utc::now().timestamp())),
+        external_id: None,
+        session_duration: Duration::seconds(DEFAULT_ROLE_DURATION_SECONDS as i64),
+        scope_down_policy: None,
+        mfa_serial: None,
+        mfa_code: None,
+    };
+    Ok(ProfileRoleCredentials::Role(role_provider))
+}
+
+/// Creates the providers of the base credentials of the `credential_source`s of profiles.
+pub(crate) trait CredentialSources {
+    type Environment: ProvideAwsCredentials + 'static;
+    type Ec2InstanceMetadata: ProvideAwsCredentials + 'static;
+    type EcsContainer: ProvideAwsCredentials + 'static;
+
+    /// The provider of `credential_source = Environment`.
+    fn environment(&self) -> Self::Environment;
+    /// The provider of `credential_source = Ec2InstanceMetadata`.
+    fn ec2_instance_metadata(&self, handle: &Handle) -> Self::Ec2InstanceMetadata;
+    /// The provider of `credential_source = EcsContainer`.
+    fn ecs_container(&self, handle: &Handle) -> Self::EcsContainer;
+}
+
+/// The `CredentialSources` reading the environment, the EC2 instance metadata and the ECS
+/// container credentials endpoint.
+struct DefaultCredentialSources;
+
+impl CredentialSources for DefaultCredentialSources {
+    type Environment = EnvironmentProvider;
+    type Ec2InstanceMetadata = InstanceMetadataProvider;
+    type EcsContainer = ContainerProvider;
+
+    fn environment(&self) -> EnvironmentProvider {
+        EnvironmentProvider
+    }
+
+    fn ec2_instance_metadata(&self, handle: &Handle) -> InstanceMetadataProvider {
+        InstanceMetadataProvider::new(handle)
+    }
+
+    fn ecs_container(&self, handle: &Handle) -> ContainerProvider {
+        ContainerProvider::new(handle)
+    }
+}
+
+/// A request dispatcher shared by the `StsClient`s of a chain of roles.
+struct SharedDispatcher<D>(Arc<D>);
+
+impl<D> Clone for SharedDispatcher<D> {
+    fn clone(&self) -> SharedDispatcher<D> {
+        SharedDispatcher(self.0.clone())
+    }
+}
+
+impl<D: DispatchSignedRequest> DispatchSignedRequest for SharedDispatcher<D> {
+    type Future = D::Future;
+
+    fn dispatch(&self, request: SignedRequest, timeout: Option<StdDuration>) -> Self::Future {
+        self.0.dispatch(request, timeout)
+    }
+}
+
+pub struct StsProfileRoleProviderFuture {
+    inner: Box<Future<Item=AwsCredentials, Error=CredentialsError>>
+}
+
+impl Future for StsProfileRoleProviderFuture {
+    type Item = AwsCredentials;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+impl ProvideAwsCredentials for StsProfileRoleProvider {
+    type Future = StsProfileRoleProviderFuture;
+
+    fn credentials(&self) -> Self::Future {
+        let inner: Box<Future<Item=AwsCredentials, Error=CredentialsError>> = match self.inner {
+            ProfileRoleCredentials::Profile(ref provider) => Box::new(provider.credentials()),
+            ProfileRoleCredentials::Role(ref provider) => Box::new(provider.credentials()),
+        };
+        StsProfileRoleProviderFuture { inner: inner }
+    }
+}
+
+fn non_empty_env_var(name: &str) -> Option<String> {
+    match env::var(name) {
+        Ok(ref value) if value.is_empty() => None,
+        Ok(value) => Some(value),
+        Err(_) => None,
+    }
+}
@@ -0,0 +1,190 @@
+extern crate rusoto_mock;
+
+use std::cell::RefCell;
+use std::env;
+use std::rc::Rc;
+
+use futures::Future;
+use tokio_core::reactor::Core;
+
+use rusoto_core::{AwsCredentials, ProfileProvider, ProvideAwsCredentials, Region, SignedRequest};
+use self::rusoto_mock::*;
+use tokio_core::reactor::Handle;
+
+use super::{StsEnvRoleChainProvider, StsProfileRoleProvider, AWS_ROLE_ARN, AWS_ROLE_SESSION_NAME};
+use super::credential::CredentialSources;
+
+const ASSUME_ROLE_RESPONSE: &str = r#"<AssumeRoleResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleResult>
+    <Credentials>
+      <SessionToken>assumed_session_token</SessionToken>
+      <SecretAccessKey>assumed_secret_key</SecretAccessKey>
+      <Expiration>2038-01-01T00:00:00Z</Expiration>
+      <AccessKeyId>ASIAASSUMEDEXAMPLE</AccessKeyId>
+    </Credentials>
+    <AssumedRoleUser>
+      <Arn>arn:aws:sts::123456789012:assumed-role/demo/rusoto-test</Arn>
+      <AssumedRoleId>AROA3XFRBF535PLBIFPI4:rusoto-test</AssumedRoleId>
+    </AssumedRoleUser>
+  </AssumeRoleResult>
+  <ResponseMetadata>
+    <RequestId>c6104cbe-af31-11e0-8154-cbc7ccf896c7</RequestId>
+  </ResponseMetadata>
+</AssumeRoleResponse>"#;
+
+fn mock_sts() -> MockRequestDispatcher {
+    MockRequestDispatcher::with_status(200)
+        .with_body(ASSUME_ROLE_RESPONSE)
+        .with_request_checker(|request: &SignedRequest| {
+            assert_eq!(Some(&Some("AssumeRole".to_owned())), request.params.get("Action"));
+            assert_eq!(Some(&Some("arn:aws:iam::123456789012:role/demo".to_owned())),
+                       request.params.get("RoleArn"));
+            assert_eq!(Some(&Some("rusoto-test".to_owned())),
+                       request.params.get("RoleSessionName"));
+        })
+}
+
+// a single test, as the environment variables are shared by all tests
+#[test]
+fn providers_assume_roles_from_env() {
+    env_role_chain_provider_assumes_role_from_env();
+    profile_role_provider_assumes_role_of_aws_profile();
+}
+
+fn env_role_chain_provider_assumes_role_from_env() {
+    let core = Core::new().unwrap();
+    env::set_var("AWS_ACCESS_KEY_ID", "base_access_key");
+    env::set_var("AWS_SECRET_ACCESS_KEY", "base_secret_key");
+    env::set_var(AWS_ROLE_ARN, "arn:aws:iam::123456789012:role/demo");
+    env::set_var(AWS_ROLE_SESSION_NAME, "rusoto-test");
+
+    let provider = StsEnvRoleChainProvider::new(mock_sts(), Region::UsEast1, &core.handle());
+    assert!(provider.assumes_role());
+    let creds = provider.credentials().wait().unwrap();
+    assert_eq!(creds.aws_access_key_id(), "ASIAASSUMEDEXAMPLE");
+    assert_eq!(creds.aws_secret_access_key(), "assumed_secret_key");
+    assert_eq!(creds.token(), &Some("assumed_session_token".to_owned()));
+
+    // without a role, the base credentials are used as they are
+    env::remove_var(AWS_ROLE_ARN);
+    let provider = StsEnvRoleChainProvider::new(mock_sts(), Region::UsEast1, &core.handle());
+    assert!(!provider.assumes_role());
+    let creds = provider.credentials().wait().unwrap();
+    assert_eq!(creds.aws_access_key_id(), "base_access_key");
+
+    // without base credentials in the environment, no role is assumed
+    env::set_var(AWS_ROLE_ARN, "arn:aws:iam::123456789012:role/demo");
+    env::remove_var("AWS_ACCESS_KEY_ID");
+    env::remove_var("AWS_SECRET_ACCESS_KEY");
+    let provider = StsEnvRoleChainProvider::new(mock_sts(), Region::UsEast1, &core.handle());
+    assert!(!provider.assumes_role());
+
+    env::remove_var(AWS_ROLE_ARN);
+    env::remove_var(AWS_ROLE_SESSION_NAME);
+}
+
+fn profile_role_provider_assumes_role_of_aws_profile() {
+    let core = Core::new().unwrap();
+    env::set_var("AWS_SHARED_CREDENTIALS_FILE", "../../credential/tests/sample-data/role_profile_credentials");
+    env::set_var("AWS_CONFIG_FILE", "../../credential/tests/sample-data/role_profile_config");
+    // assumes role/chained using the credentials of role/example, assumed using [base]
+    env::set_var("AWS_PROFILE", "chained");
+
+    let role_arns = Rc::new(RefCell::new(Vec::new()));
+    let recorded_role_arns = role_arns.clone();
+    let sts = MockRequestDispatcher::with_status(200)
+        .with_body(ASSUME_ROLE_RESPONSE)
+        .with_request_checker(move |request: &SignedRequest| {
+            assert_eq!(Some(&Some("AssumeRole".to_owned())), request.params.get("Action"));
+            let role_arn = request.params.get("RoleArn").cloned().unwrap().unwrap();
+            recorded_role_arns.borrow_mut().push(role_arn);
+        });
+    let provider = StsProfileRoleProvider::new(sts, Region::UsEast1, &core.handle()).unwrap();
+    assert!(provider.assumes_role());
+    let creds = provider.credentials().wait().unwrap();
+    assert_eq!(creds.aws_access_key_id(), "ASIAASSUMEDEXAMPLE");
+    assert_eq!(creds.aws_secret_access_key(), "assumed_secret_key");
+    assert_eq!(creds.token(), &Some("assumed_session_token".to_owned()));
+    assert_eq!(*role_arns.borrow(), vec!["arn:aws:iam::123456789012:role/example".to_owned(),
+                                        "arn:aws:iam::123456789012:role/chained".to_owned()]);
+
+    // a profile without a role is used as it is
+    env::set_var("AWS_PROFILE", "base");
+    let provider = StsProfileRoleProvider::new(mock_sts(), Region::UsEast1, &core.handle()).unwrap();
+    assert!(!provider.assumes_role());
+    assert_eq!(provider.credentials().wait().unwrap().aws_access_key_id(), "base_access_key");
+
+    env::set_var("AWS_PROFILE", "cycle_a");
+    let error = StsProfileRoleProvider::new(mock_sts(), Region::UsEast1, &core.handle()).err().unwrap();
+    assert_eq!(error.message, "Cycle in source_profile chain: cycle_a -> cycle_b -> cycle_a");
+
+    env::remove_var("AWS_PROFILE");
+    env::remove_var("AWS_CONFIG_FILE");
+    env::remove_var("AWS_SHARED_CREDENTIALS_FILE");
+}
+
+/// `CredentialSources` with fixed credentials, whose access key id names the source.
+struct StubCredentialSources;
+
+impl CredentialSources for StubCredentialSources {
+    type Environment = AwsCredentials;
+    type Ec2InstanceMetadata = AwsCredentials;
+    type EcsContainer = AwsCredentials;
+
+    fn environment(&self) -> AwsCredentials {
+        AwsCredentials::new("environment_access_key", "environment_secret_key", None, None)
+    }
+
+    fn ec2_instance_metadata(&self, _handle: &Handle) -> AwsCredentials {
+        AwsCredentials::new("ec2_access_key", "ec2_secret_key", None, None)
+    }
+
+    fn ecs_container(&self, _handle: &Handle) -> AwsCredentials {
+        AwsCredentials::new("ecs_access_key", "ecs_secret_key", None, None)
+    }
+}
+
+/// Assumes the role of `profile` with the stubbed credential sources and returns the access
+/// key id the `AssumeRole` request was signed with.
+fn credential_source_access_key(profile: &str) -> String {
+    let core = Core::new().unwrap();
+    let mut profile_provider = ProfileProvider::with_configuration(
+        "../../credential/tests/sample-data/role_profile_credentials", profile);
+    profile_provider.set_config_file_path("../../credential/tests/sample-data/role_profile_config");
+
+    let access_keys = Rc::new(RefCell::new(Vec::new()));
+    let recorded_access_keys = access_keys.clone();
+    let sts = MockRequestDispatcher::with_status(200)
+        .with_body(ASSUME_ROLE_RESPONSE)
+        .with_request_checker(move |request: &SignedRequest| {
+            assert_eq!(Some(&Some("AssumeRole".to_owned())), request.params.get("Action"));
+            let authorization = String::from_utf8(request.headers()["authorization"][0].clone())
+                .unwrap();
+            let credential = authorization.split("Credential=").nth(1).unwrap();
+            recorded_access_keys.borrow_mut().push(credential.split('/').next().unwrap().to_owned());
+        });
+    let provider = StsProfileRoleProvider::with_credential_sources(
+        sts, Region::UsEast1, profile_provider, &StubCredentialSources, &core.handle()).unwrap();
+    assert!(provider.assumes_role());
+    let creds = provider.credentials().wait().unwrap();
+    assert_eq!(creds.aws_access_key_id(), "ASIAASSUMEDEXAMPLE");
+
+    let access_keys = access_keys.borrow();
+    assert_eq!(access_keys.len(), 1);
+    access_keys[0].clone()
+}
+
+#[test]
+fn profile_role_provider_credential_source_environment() {
+    assert_eq!(credential_source_access_key("from_environment"), "environment_access_key");
+}
+
+#[test]
+fn profile_role_provider_credential_source_ec2_instance_metadata() {
+    assert_eq!(credential_source_access_key("from_ec2"), "ec2_access_key");
+}
+
+#[test]
+fn profile_role_provider_credential_source_ecs_container() {
+    assert_eq!(credential_source_access_key("from_ecs"), "ecs_access_key");
+}
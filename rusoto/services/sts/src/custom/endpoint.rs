@@ -0,0 +1,123 @@
+use std::str::FromStr;
+
+use rusoto_core::{CredentialsError, Region};
+use rusoto_core::credential::ProfileProvider;
+
+/// The config file key selecting the STS endpoints, `regional` or `legacy`.
+pub const STS_REGIONAL_ENDPOINTS: &str = "sts_regional_endpoints";
+
+/// Whether STS requests are sent to the endpoint of the client's region, like
+/// `sts.eu-west-1.amazonaws.com`, or, for the regions which used it before regional endpoints
+/// were introduced, to the global endpoint `sts.amazonaws.com`.
+///
+/// AWS recommends the regional endpoints for lower latency and higher reliability. As the
+/// endpoint is determined by the region of the [StsClient](struct.StsClient.html), create the
+/// client used by the STS credential providers with the region returned by `region`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StsRegionalEndpoints {
+    /// Use the endpoint of the region.
+    Regional,
+    /// Use the global endpoint for the regions which used it before regional endpoints
+    /// were introduced.
+    Legacy,
+}
+
+impl Default for StsRegionalEndpoints {
+    fn default() -> StsRegionalEndpoints {
+        StsRegionalEndpoints::Regional
+    }
+}
+
+impl FromStr for StsRegionalEndpoints {
+    type Err = CredentialsError;
+
+    fn from_str(s: &str) -> Result<StsRegionalEndpoints, CredentialsError> {
+        match &s.to_ascii_lowercase()[..] {
+            "regional" => Ok(StsRegionalEndpoints::Regional),
+            "legacy" => Ok(StsRegionalEndpoints::Legacy),
+            _ => Err(CredentialsError::new(format!(
+                "Invalid {} {:?}, expected regional or legacy",
+                STS_REGIONAL_ENDPOINTS, s
+            ))),
+        }
+    }
+}
+
+impl StsRegionalEndpoints {
+    /// Read `sts_regional_endpoints` from the configured profile of the given provider, which
+    /// may be set in the config file, defaulting to `Regional`.
+    pub fn from_profile(provider: &ProfileProvider) -> Result<StsRegionalEndpoints, CredentialsError> {
+        let profiles = try!(provider.parse_all());
+        match profiles.get(provider.profile()).and_then(|profile| profile.get(STS_REGIONAL_ENDPOINTS)) {
+            Some(value) => value.parse(),
+            None => Ok(StsRegionalEndpoints::default()),
+        }
+    }
+
+    /// Get the region to create an [StsClient](struct.StsClient.html) for `region` with, so it
+    /// uses the selected endpoint.
+    pub fn region(&self, region: &Region) -> Region {
+        match (*self, region) {
+            (StsRegionalEndpoints::Legacy, &Region::ApNortheast1) |
+            (StsRegionalEndpoints::Legacy, &Region::ApSouth1) |
+            (StsRegionalEndpoints::Legacy, &Region::ApSoutheast1) |
+            (StsRegionalEndpoints::Legacy, &Region::ApSoutheast2) |
+            (StsRegionalEndpoints::Legacy, &Region::CaCentral1) |
+            (StsRegionalEndpoints::Legacy, &Region::EuCentral1) |
+            (StsRegionalEndpoints::Legacy, &Region::EuWest1) |
+            (StsRegionalEndpoints::Legacy, &Region::EuWest2) |
+            (StsRegionalEndpoints::Legacy, &Region::EuWest3) |
+            (StsRegionalEndpoints::Legacy, &Region::SaEast1) |
+            (StsRegionalEndpoints::Legacy, &Region::UsEast1) |
+            (StsRegionalEndpoints::Legacy, &Region::UsEast2) |
+            (StsRegionalEndpoints::Legacy, &Region::UsWest1) |
+            (StsRegionalEndpoints::Legacy, &Region::UsWest2) => Region::Custom {
+                // requests to the global endpoint are signed for us-east-1
+                name: Region::UsEast1.name().to_owned(),
+                endpoint: "https://sts.amazonaws.com".to_owned(),
+            },
+            _ => region.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rusoto_core::{Region, SignedRequest};
+    use rusoto_core::credential::ProfileProvider;
+
+    use super::*;
+
+    fn hostname(endpoints: StsRegionalEndpoints, region: &Region) -> String {
+        SignedRequest::new("POST", "sts", &endpoints.region(region), "/").hostname()
+    }
+
+    #[test]
+    fn endpoint_hostnames() {
+        assert_eq!(hostname(StsRegionalEndpoints::Regional, &Region::EuWest1), "sts.eu-west-1.amazonaws.com");
+        assert_eq!(hostname(StsRegionalEndpoints::Legacy, &Region::EuWest1), "sts.amazonaws.com");
+        assert_eq!(StsRegionalEndpoints::Legacy.region(&Region::EuWest1).name(), "us-east-1");
+        // regions introduced after the regional endpoints never used the global one
+        assert_eq!(hostname(StsRegionalEndpoints::Legacy, &Region::ApNortheast2), "sts.ap-northeast-2.amazonaws.com");
+        assert_eq!(hostname(StsRegionalEndpoints::Legacy, &Region::CnNorth1), "sts.cn-north-1.amazonaws.com.cn");
+    }
+
+    #[test]
+    fn endpoints_from_profile() {
+        let mut provider = ProfileProvider::with_configuration(
+            "../../credential/tests/sample-data/multiple_profile_credentials",
+            "dev",
+        );
+        provider.add_profile("dev", HashMap::new());
+        assert_eq!(StsRegionalEndpoints::from_profile(&provider).ok(), Some(StsRegionalEndpoints::Regional));
+
+        let mut properties = HashMap::new();
+        properties.insert(STS_REGIONAL_ENDPOINTS.to_owned(), "legacy".to_owned());
+        provider.add_profile("dev", properties);
+        assert_eq!(StsRegionalEndpoints::from_profile(&provider).ok(), Some(StsRegionalEndpoints::Legacy));
+
+        assert!("global".parse::<StsRegionalEndpoints>().is_err());
+    }
+}
@@ -1,8 +1,17 @@
 mod credential;
+mod endpoint;
 
 pub use self::credential::{
     StsSessionCredentialsProvider,
     StsAssumeRoleSessionCredentialsProvider,
     StsWebIdentityFederationSessionCredentialsProvider,
+    StsEnvRoleChainProvider,
+    StsProfileRoleProvider,
     NewAwsCredsForStsCreds,
-};
\ No newline at end of file
+    AWS_ROLE_ARN,
+    AWS_ROLE_SESSION_NAME,
+};
+pub use self::endpoint::{StsRegionalEndpoints, STS_REGIONAL_ENDPOINTS};
+
+#[cfg(test)]
+mod custom_tests;
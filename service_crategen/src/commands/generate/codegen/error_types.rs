@@ -157,7 +157,7 @@ impl GenerateErrorTypes for XmlErrorTypes {
                 impl {type_name} {{
                     pub fn from_body(body: &str) -> {type_name} {{
                         let reader = EventReader::new(body.as_bytes());
-                        let mut stack = XmlResponse::new(reader.into_iter().peekable());
+                        let mut stack = XmlResponse::new(reader);
                         find_start_element(&mut stack);
                         match Self::deserialize(&mut stack) {{
                             Ok(parsed_error) => {{
@@ -150,8 +150,7 @@ fn xml_body_parser(output_shape: &str,
                     response.body.as_slice(),
                     ParserConfig::new().trim_whitespace(true)
                 );
-                let mut stack = XmlResponse::new(reader.into_iter().peekable());
-                let _start_document = stack.next();
+                let mut stack = XmlResponse::new(reader);
                 let actual_tag_name = try!(peek_at_name(&mut stack));
                 {deserialize}
             }}